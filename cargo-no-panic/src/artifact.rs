@@ -0,0 +1,115 @@
+// Find no-panic errors in library artifacts, which Cargo never links into
+// anything on its own: the object files inside of an rlib or staticlib, or a
+// cdylib, which is allowed to keep undefined symbols. A guard that survived
+// optimization shows up there as an undefined reference to its symbol, same
+// as the one the linker would complain about.
+//
+// Objects that hold only LLVM bitcode, as Cargo builds for LTO, have not been
+// optimized yet, so they cannot be checked. They are an error rather than
+// passing for free of panics.
+//
+// A guard listed in the baseline panics instead, as in abort mode, so if it
+// survived, its message is in the data of the object code that prints it, of
+// an executable as much as of a library. Only the sections of object files are
+// searched, since the metadata of an rlib holds the message as well, for the
+// crates that inline the function.
+
+use crate::parse::{self, Failure};
+use object::read::archive::ArchiveFile;
+use object::{Object, ObjectSection, ObjectSymbol, SectionKind};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+// Whether `path` is a library Cargo produced that could contain object code,
+// as opposed to metadata or an executable.
+pub fn is_library(path: &Path) -> bool {
+    let extension = path.extension().and_then(|ext| ext.to_str());
+    matches!(
+        extension,
+        Some("rlib" | "a" | "lib" | "so" | "dylib" | "dll"),
+    )
+}
+
+pub fn failures(path: &Path) -> io::Result<Vec<Failure>> {
+    let mut failures = Vec::new();
+    objects(path, |data| scan(data, &mut failures))?;
+    Ok(failures)
+}
+
+// Failures whose message is kept as data, to be printed at runtime.
+pub fn messages(path: &Path) -> io::Result<Vec<Failure>> {
+    let mut failures = Vec::new();
+    objects(path, |data| {
+        let Ok(file) = object::File::parse(data) else {
+            return Ok(());
+        };
+        for section in file.sections() {
+            if !matches!(
+                section.kind(),
+                SectionKind::Data
+                    | SectionKind::ReadOnlyData
+                    | SectionKind::ReadOnlyDataWithRel
+                    | SectionKind::ReadOnlyString,
+            ) {
+                continue;
+            }
+            let Ok(data) = section.data() else {
+                continue;
+            };
+            for failure in parse::messages(data) {
+                if !failures.contains(&failure) {
+                    failures.push(failure);
+                }
+            }
+        }
+        Ok(())
+    })?;
+    Ok(failures)
+}
+
+// Call `f` with the data of each member of an archive, or else of the file.
+fn objects(path: &Path, mut f: impl FnMut(&[u8]) -> io::Result<()>) -> io::Result<()> {
+    let data = fs::read(path)?;
+    match ArchiveFile::parse(&*data) {
+        Ok(archive) => {
+            for member in archive.members() {
+                let member = member.map_err(io::Error::other)?;
+                let data = member.data(&*data).map_err(io::Error::other)?;
+                f(data)?;
+            }
+            Ok(())
+        }
+        Err(_) => f(&data),
+    }
+}
+
+fn scan(data: &[u8], failures: &mut Vec<Failure>) -> io::Result<()> {
+    if is_bitcode(data) {
+        return Err(io::Error::other(
+            "contains LLVM bitcode instead of object code, as built for LTO; check with `lto = false`",
+        ));
+    }
+    let Ok(file) = object::File::parse(data) else {
+        return Ok(());
+    };
+    for symbol in file.symbols().chain(file.dynamic_symbols()) {
+        if !symbol.is_undefined() {
+            continue;
+        }
+        let Ok(name) = symbol.name() else {
+            continue;
+        };
+        for failure in parse::failures(name) {
+            if !failures.contains(&failure) {
+                failures.push(failure);
+            }
+        }
+    }
+    Ok(())
+}
+
+// Raw bitcode, or bitcode in the wrapper that Apple's tools use.
+fn is_bitcode(data: &[u8]) -> bool {
+    data.starts_with(b"BC\xC0\xDE") || data.starts_with(&[0xDE, 0xC0, 0x17, 0x0B])
+}
@@ -0,0 +1,177 @@
+// Failures that are known and accepted for now, for adopting no-panic
+// gradually. They are listed in a file at the root of the workspace, one per
+// line, by module and by what was checked:
+//
+//     demo_crate::decode: function `Reader::read`
+//     demo_crate::decode: statement in function `skip` at src/decode.rs:40:9
+//     demo_crate: closure at src/lib.rs:40:17
+//
+// Blocks, closures and statements have no name of their own, so they are
+// listed with their location too, and so are functions that share a name in
+// the same module. Such an entry stops matching once it moves. Lines that are
+// empty or start with `#` are ignored.
+//
+// A listed failure is not reported, and cargo no-panic builds it as in abort
+// mode, so that what it is in still links. Its message is then in the object
+// code instead. One that is no longer there fails the build, so that the entry
+// gets removed and the function cannot regress unnoticed.
+
+use crate::parse::Failure;
+use crate::source::Function;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::Path;
+
+pub const FILE: &str = "no-panic.baseline";
+
+pub struct Baseline {
+    entries: BTreeSet<String>,
+}
+
+impl Baseline {
+    pub fn parse(content: &str) -> Self {
+        let entries = content
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(str::to_owned)
+            .collect();
+        Baseline { entries }
+    }
+
+    pub fn contains(&self, failure: &Failure) -> bool {
+        let entries = [named(failure), located(failure)];
+        entries
+            .into_iter()
+            .flatten()
+            .any(|entry| self.entries.contains(&entry))
+    }
+
+    // Listed entries that are not among the failures, leaving out those of
+    // crates that were not checked.
+    pub fn stale<'a>(&'a self, failures: &[&Failure], checked: &[String]) -> Vec<&'a str> {
+        let failed: BTreeSet<String> = failures
+            .iter()
+            .flat_map(|failure| [named(failure), located(failure)])
+            .flatten()
+            .collect();
+        self.entries
+            .iter()
+            .filter(|entry| !failed.contains(*entry))
+            .filter(|entry| checked.iter().any(|krate| krate == crate_name(entry)))
+            .map(String::as_str)
+            .collect()
+    }
+
+    // The value of NO_PANIC_BASELINE, which tells the attribute which of its
+    // guards are listed: one line each, with the module, the location and the
+    // generic arguments of an instantiation, as in
+    // `demo_crate::decode src/decode.rs:27:5::<u8>`. An entry without a
+    // location gets that of the function of its name among `functions`.
+    pub fn keys(&self, functions: &[Function]) -> String {
+        let mut keys = String::new();
+        for entry in &self.entries {
+            let Some((module, rest)) = entry.split_once(": ") else {
+                continue;
+            };
+            let (subject, location) = match rest.rsplit_once(" at ") {
+                Some((subject, location)) if !location.ends_with('`') => (subject, Some(location)),
+                _ => (rest, None),
+            };
+            let (name, instantiation) = split_name(subject);
+            let locations: Vec<&str> = match location {
+                Some(location) => vec![location],
+                None => functions
+                    .iter()
+                    .filter(|function| function.module == module && function.name == name)
+                    .map(|function| function.location.as_str())
+                    .collect(),
+            };
+            for location in locations {
+                keys.push_str(module);
+                keys.push(' ');
+                keys.push_str(location);
+                keys.push_str(instantiation);
+                keys.push('\n');
+            }
+        }
+        keys
+    }
+}
+
+pub fn load(root: &Path) -> io::Result<Option<Baseline>> {
+    match fs::read_to_string(root.join(FILE)) {
+        Ok(content) => Ok(Some(Baseline::parse(&content))),
+        Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+// The baseline that lists exactly these failures, each by its name unless
+// another function of `functions` or another failure has the same one.
+pub fn render(failures: &[&Failure], functions: &[Function]) -> String {
+    let mut names = BTreeMap::new();
+    for failure in failures {
+        if let (Some(name), Some(location)) = (named(failure), failure.field("location")) {
+            let locations: &mut BTreeSet<&str> = names.entry(name).or_default();
+            locations.insert(location);
+        }
+    }
+    let entries: BTreeSet<String> = failures
+        .iter()
+        .filter_map(|failure| match named(failure) {
+            Some(name) if names[&name].len() == 1 && !shared(failure, functions) => Some(name),
+            _ => located(failure),
+        })
+        .collect();
+    let mut content = String::new();
+    for entry in entries {
+        content.push_str(&entry);
+        content.push('\n');
+    }
+    content
+}
+
+// Whether more than one function in the module of the failure has its name.
+fn shared(failure: &Failure, functions: &[Function]) -> bool {
+    let module = failure.field("module").unwrap_or_default();
+    let (name, _instantiation) = split_name(&failure.subject);
+    let count = functions
+        .iter()
+        .filter(|function| function.module == module && function.name == name)
+        .count();
+    count > 1
+}
+
+// `nth` for ``function `nth::<u8>` `` or ``statement in function `nth` ``,
+// along with `::<u8>`, the generic arguments of an instantiation.
+fn split_name(subject: &str) -> (&str, &str) {
+    let name = subject
+        .split_once('`')
+        .and_then(|(_kind, quoted)| quoted.strip_suffix('`'))
+        .unwrap_or_default();
+    name.split_at(name.find("::<").unwrap_or(name.len()))
+}
+
+// The entry of a function by its name alone.
+fn named(failure: &Failure) -> Option<String> {
+    if !failure.subject.starts_with("function ") {
+        return None;
+    }
+    let module = failure.field("module")?;
+    Some(format!("{}: {}", module, failure.subject))
+}
+
+fn located(failure: &Failure) -> Option<String> {
+    let module = failure.field("module")?;
+    let location = failure.field("location")?;
+    Some(format!("{}: {} at {}", module, failure.subject, location))
+}
+
+fn crate_name(entry: &str) -> &str {
+    let module = entry
+        .split_once(": ")
+        .map_or(entry, |(module, _rest)| module);
+    module.split("::").next().unwrap_or(module)
+}
@@ -0,0 +1,100 @@
+// What kind of panic a call chain ends in, going by the panic entry point,
+// and the usual way to get rid of it.
+
+use crate::explain::{Chain, End};
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Cause {
+    BoundsCheck,
+    Overflow,
+    DivisionByZero,
+    Unwrap,
+    Panic,
+    Allocation,
+    RefCellBorrow,
+    // A call of a function from another crate that was not inlined, which
+    // might panic for all the optimizer knows.
+    Opaque,
+}
+
+impl Cause {
+    pub fn of(chain: &Chain) -> Option<Self> {
+        let function = match &chain.end {
+            End::Opaque(_) => return Some(Cause::Opaque),
+            End::Panic(function) => function,
+        };
+        let path = function.split('<').next().unwrap_or(function);
+        let name = path.trim_end_matches(':').rsplit("::").next()?;
+        let cause = match name {
+            "panic_bounds_check"
+            | "slice_index_fail"
+            | "slice_start_index_len_fail"
+            | "slice_end_index_len_fail"
+            | "slice_index_order_fail"
+            | "str_index_overflow_fail"
+            | "slice_error_fail" => Cause::BoundsCheck,
+            "panic_const_div_by_zero" | "panic_const_rem_by_zero" => Cause::DivisionByZero,
+            _ if name.starts_with("panic_const_") && name.ends_with("_overflow") => Cause::Overflow,
+            "unwrap_failed" | "expect_failed" => Cause::Unwrap,
+            "capacity_overflow" | "handle_error" | "handle_alloc_error" => Cause::Allocation,
+            "panic_already_borrowed" | "panic_already_mutably_borrowed" => Cause::RefCellBorrow,
+            "panic_fmt"
+            | "panic"
+            | "panic_str"
+            | "panic_display"
+            | "panic_explicit"
+            | "begin_panic"
+            | "assert_failed"
+            | "unreachable_display" => {
+                // A panic in the standard library on behalf of something that
+                // does not fit any of the above.
+                let in_std = chain
+                    .steps
+                    .last()
+                    .is_some_and(|step| step.location.starts_with("library/"));
+                if in_std {
+                    return None;
+                }
+                Cause::Panic
+            }
+            _ => return None,
+        };
+        Some(cause)
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            Cause::BoundsCheck => "index out of bounds",
+            Cause::Overflow => "arithmetic overflow",
+            Cause::DivisionByZero => "division by zero",
+            Cause::Unwrap => "`unwrap` or `expect` of `None` or `Err`",
+            Cause::Panic => "explicit `panic!` or assertion",
+            Cause::Allocation => "allocation failure or capacity overflow",
+            Cause::RefCellBorrow => "`RefCell` already borrowed",
+            Cause::Opaque => "call of a function from another crate that was not inlined",
+        }
+    }
+
+    pub fn help(self) -> &'static str {
+        match self {
+            Cause::BoundsCheck => {
+                "index with `get()`, which returns an `Option`, or check the index against the length where the optimizer can see it"
+            }
+            Cause::Overflow => "use `checked_*`, `wrapping_*` or `saturating_*` arithmetic",
+            Cause::DivisionByZero => {
+                "use `checked_div` or `checked_rem`, or divide by a `NonZero` integer"
+            }
+            Cause::Unwrap => {
+                "handle the `None` or `Err` case, such as with `?`, `match` or `unwrap_or`"
+            }
+            Cause::Panic => "return an error instead, or make the condition provably false",
+            Cause::Allocation => {
+                "allocate before entering the checked code, or use `try_reserve` to handle the failure"
+            }
+            Cause::RefCellBorrow => "use `try_borrow` or `try_borrow_mut`, which return a `Result`",
+            Cause::Opaque => {
+                "mark the callee `#[inline]` if it is yours, or else set `lto = \"thin\"` in the release profile"
+            }
+        }
+    }
+}
@@ -0,0 +1,105 @@
+// How much of the public API of a package is annotated, for a policy that all
+// of it should be. The threshold for failing comes from the package's own
+// manifest:
+//
+//     [package.metadata.no-panic]
+//     min-coverage = 90
+
+use crate::source::Function;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Status {
+    Annotated,
+    // Annotated, but a verification build does not check it on its own, so
+    // it is checked only as far as something calls it, which may be nothing.
+    Unverified,
+    Missing,
+}
+
+impl Status {
+    // A guess from the source alone, of the same rules that the attribute
+    // follows to decide what a verification build checks on its own. Nothing
+    // is built, so it says nothing about whether the function is panic-free,
+    // and a function it counts as annotated may still fail to be retained,
+    // such as a `verify` copy that does not compile where it is nested.
+    pub fn of(function: &Function) -> Self {
+        if !function.annotated {
+            Status::Missing
+        } else if function.standalone {
+            Status::Annotated
+        } else {
+            Status::Unverified
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Status::Annotated => "annotated",
+            Status::Unverified => "unverified",
+            Status::Missing => "missing",
+        }
+    }
+}
+
+// The percentage of the functions that are annotated, verified or not.
+pub fn percentage(functions: &[&Function]) -> f64 {
+    if functions.is_empty() {
+        return 100.0;
+    }
+    let annotated = functions
+        .iter()
+        .filter(|function| function.annotated)
+        .count();
+    annotated as f64 * 100.0 / functions.len() as f64
+}
+
+// `min-coverage` from the `metadata` table of a package's manifest.
+pub fn threshold(metadata: Option<&serde_json::Value>) -> Option<f64> {
+    metadata?.get("no-panic")?.get("min-coverage")?.as_f64()
+}
+
+// List the public functions of a package with the status of each. Returns
+// whether the coverage is at least the threshold.
+pub fn print(package: &str, functions: &[&Function], threshold: Option<f64>) -> bool {
+    println!("no-panic coverage of `{}`:", package);
+    for function in functions {
+        println!(
+            "    {:<10}  {}::{} at {}",
+            Status::of(function).label(),
+            function.module,
+            function.name,
+            function.location,
+        );
+    }
+    let annotated = functions.iter().filter(|f| f.annotated).count();
+    let unverified = functions
+        .iter()
+        .filter(|f| Status::of(f) == Status::Unverified)
+        .count();
+    let percentage = percentage(functions);
+    print!(
+        "    {} of {} public functions annotated ({:.1}%)",
+        annotated,
+        functions.len(),
+        percentage,
+    );
+    if unverified > 0 {
+        print!(
+            ", {} of them not checked on {} own",
+            unverified,
+            if unverified == 1 { "its" } else { "their" },
+        );
+    }
+    println!();
+    println!();
+    match threshold {
+        Some(threshold) if percentage < threshold => {
+            eprintln!(
+                "error: no-panic coverage of `{}` is {:.1}%, below its min-coverage of {}%",
+                package, percentage, threshold,
+            );
+            false
+        }
+        _ => true,
+    }
+}
@@ -0,0 +1,290 @@
+// Find out how a checked function can panic, from the LLVM IR of the build.
+//
+// The guard's drop calls the undefined symbol of the failure, directly or
+// through the drop of a generic guard. Wherever that drop is reached from a
+// landing pad, the calls that unwind to the landing pad are how the checked
+// code can panic. From each such call this follows calls, shortest first,
+// until one reaches a function that never returns and may unwind: a panic
+// entry point like `core::panicking::panic_bounds_check`. A call of a function
+// whose IR is not available, usually one from another crate that was not
+// inlined, ends the search too.
+
+use crate::ir::{self, Frame, Function};
+use crate::parse::{self, Failure};
+use crate::remark::Reason;
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub struct Chain {
+    // From the checked function to the last function before the end.
+    pub steps: Vec<Step>,
+    pub end: End,
+    // How many other calls of the checked function can panic.
+    pub others: usize,
+}
+
+#[derive(Clone)]
+pub struct Step {
+    pub function: String,
+    // Where it makes the next call.
+    pub location: String,
+    // Inlined into the step before it.
+    pub inlined: bool,
+    // The symbol of the function called at `location`, unless that call was
+    // inlined into this one.
+    pub callee: Option<String>,
+    // Why that call was not inlined, from the optimization remarks.
+    pub reason: Option<Reason>,
+}
+
+pub enum End {
+    Panic(String),
+    // A function whose body is not known.
+    Opaque(String),
+}
+
+// Read every `.ll` file under `dir`. A function defined in more than one
+// module is taken from the newest.
+pub fn load(dir: &Path) -> io::Result<HashMap<String, Function>> {
+    let mut files = Vec::new();
+    find_ir(dir, &mut files)?;
+    files.sort_by_key(|(modified, _path)| Reverse(*modified));
+    let mut functions = HashMap::new();
+    for (module, (_modified, path)) in files.into_iter().enumerate() {
+        let ir = fs::read_to_string(&path)?;
+        for (symbol, mut function) in ir::parse(&ir) {
+            function.module = module;
+            match functions.get(&symbol) {
+                Some(Function { defined: true, .. }) => {}
+                Some(Function { defined: false, .. }) if !function.defined => {}
+                _ => {
+                    functions.insert(symbol, function);
+                }
+            }
+        }
+    }
+    Ok(functions)
+}
+
+fn find_ir(dir: &Path, files: &mut Vec<(std::time::SystemTime, PathBuf)>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            find_ir(&path, files)?;
+        } else if path.extension().is_some_and(|ext| ext == "ll") {
+            files.push((entry.metadata()?.modified()?, path));
+        }
+    }
+    Ok(())
+}
+
+pub fn chain(failure: &Failure, functions: &HashMap<String, Function>) -> Option<Chain> {
+    let triggers: HashSet<&str> = functions
+        .keys()
+        .filter(|symbol| {
+            parse::failures(symbol)
+                .iter()
+                .any(|found| is_failure(found, failure))
+        })
+        .map(String::as_str)
+        .collect();
+    // Drops of generic guards, which are not inlined.
+    let guards: HashSet<&str> = functions
+        .values()
+        .filter(|function| {
+            function.defined
+                && function.display_name().contains("__NoPanic")
+                && function
+                    .calls
+                    .iter()
+                    .any(|call| triggers.contains(call.callee.as_str()))
+        })
+        .map(|function| function.symbol.as_str())
+        .collect();
+    let armed = |symbol: &str| triggers.contains(symbol) || guards.contains(symbol);
+
+    let mut candidates = Vec::new();
+    for function in functions.values() {
+        if !function.defined || guards.contains(function.symbol.as_str()) {
+            continue;
+        }
+        let reaches = reaches_guard(function, &armed);
+        for call in &function.calls {
+            let unwinds_to_guard = call.unwind.is_some_and(|block| reaches[block]);
+            if !unwinds_to_guard || call.nounwind || armed(&call.callee) {
+                continue;
+            }
+            let mut steps = steps(&call.frames, &call.callee);
+            trim(&mut steps, failure);
+            let callee_name = call.name.as_deref().unwrap_or(&call.callee);
+            let start = (call.callee.as_str(), callee_name, function.module);
+            if let Some((rest, end)) = search(start, functions, &armed) {
+                steps.extend(rest);
+                fold_closure(&mut steps);
+                candidates.push((steps, end));
+            }
+        }
+    }
+
+    let others = candidates.len().checked_sub(1)?;
+    let (steps, end) = candidates.into_iter().min_by_key(|(steps, end)| {
+        let opaque = matches!(end, End::Opaque(_));
+        (opaque, steps.len())
+    })?;
+    Some(Chain { steps, end, others })
+}
+
+// Which blocks of the function lead to a call of the guard's drop.
+fn reaches_guard(function: &Function, armed: &dyn Fn(&str) -> bool) -> Vec<bool> {
+    let mut reaches = vec![false; function.blocks.len()];
+    for call in &function.calls {
+        if armed(&call.callee) {
+            reaches[call.block] = true;
+        }
+    }
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for (block, successors) in function.blocks.iter().enumerate() {
+            if !reaches[block] && successors.iter().any(|&successor| reaches[successor]) {
+                reaches[block] = true;
+                changed = true;
+            }
+        }
+    }
+    reaches
+}
+
+// Breadth-first from a callee to the nearest panic entry point, or else to
+// the nearest call of a function defined in another module than its caller,
+// which the optimizer could not see into.
+fn search(
+    (start, start_name, caller_module): (&str, &str, usize),
+    functions: &HashMap<String, Function>,
+    armed: &dyn Fn(&str) -> bool,
+) -> Option<(Vec<Step>, End)> {
+    let mut queue = VecDeque::from([(start, start_name, caller_module, Vec::new())]);
+    let mut visited = HashSet::from([start]);
+    let mut opaque = None;
+    while let Some((symbol, name, caller_module, steps)) = queue.pop_front() {
+        let Some(function) = functions.get(symbol) else {
+            opaque.get_or_insert_with(|| (steps, End::Opaque(name.to_owned())));
+            continue;
+        };
+        if function.noreturn && !function.nounwind {
+            return Some((steps, End::Panic(name.to_owned())));
+        }
+        if !function.defined || function.module != caller_module {
+            if opaque.is_none() {
+                opaque = Some((steps.clone(), End::Opaque(name.to_owned())));
+            }
+            if !function.defined {
+                continue;
+            }
+        }
+        for call in &function.calls {
+            let callee = call.callee.as_str();
+            let nounwind = call.nounwind || functions.get(callee).is_some_and(|f| f.nounwind);
+            if nounwind || armed(callee) || !visited.insert(callee) {
+                continue;
+            }
+            let mut next = steps.clone();
+            next.extend(self::steps(&call.frames, callee));
+            if call.noreturn && !nounwind && !functions.contains_key(callee) {
+                return Some((next, End::Panic(callee.to_owned())));
+            }
+            let name = call.name.as_deref().unwrap_or(callee);
+            queue.push_back((callee, name, function.module, next));
+        }
+    }
+    opaque
+}
+
+// The frames of one call, as steps that all but the first were inlined into.
+// The last is the one making the call.
+fn steps(frames: &[Frame], callee: &str) -> Vec<Step> {
+    frames
+        .iter()
+        .enumerate()
+        .map(|(i, frame)| Step {
+            function: frame.function.clone(),
+            location: format!("{}:{}:{}", frame.file, frame.line, frame.column),
+            inlined: i > 0,
+            callee: (i + 1 == frames.len()).then(|| callee.to_owned()),
+            reason: None,
+        })
+        .collect()
+}
+
+// Whether `found` in a symbol is `failure`, which may name a method by its
+// type where the symbol has only its name.
+fn is_failure(found: &Failure, failure: &Failure) -> bool {
+    if found.fields != failure.fields {
+        return false;
+    }
+    match (
+        found.subject.split_once('`'),
+        failure.subject.split_once('`'),
+    ) {
+        (Some((found_kind, found_name)), Some((kind, name))) => {
+            kind == found_kind
+                && (name == found_name || name.ends_with(&format!("::{}", found_name)))
+        }
+        _ => found.subject == failure.subject,
+    }
+}
+
+// Drop the callers of the checked function, into which it was inlined.
+fn trim(steps: &mut Vec<Step>, failure: &Failure) {
+    let Some(target) = checked_name(&failure.subject) else {
+        return;
+    };
+    let file = failure
+        .field("location")
+        .and_then(|location| location.split(':').next())
+        .unwrap_or_default();
+    let start = steps.iter().position(|step| {
+        let mut segments = base_path(&step.function).rsplit("::");
+        let last = segments.next().unwrap_or_default();
+        let is_target = last == target || last.starts_with('{') && segments.next() == Some(target);
+        is_target && step.location.starts_with(file)
+    });
+    if let Some(start) = start {
+        steps.drain(..start);
+        steps[0].inlined = false;
+    }
+}
+
+// The body of a non-async function is a closure generated by no-panic, which
+// is not interesting to see as a step of its own.
+fn fold_closure(steps: &mut Vec<Step>) {
+    let is_body = |function: &str, closure: &str| {
+        let function = base_path(function).rsplit("::").next();
+        let closure = base_path(closure).rsplit_once("::{closure#");
+        closure.is_some_and(|(path, _)| path.rsplit("::").next() == function)
+    };
+    if steps.len() >= 2 && is_body(&steps[0].function, &steps[1].function) {
+        let closure = steps.remove(1);
+        steps[0].location = closure.location;
+        steps[0].callee = closure.callee;
+    }
+}
+
+// `demo` for ``function `demo` ``, ``statement in function `demo` `` or
+// ``function `Wrap::demo::<u8>` ``.
+fn checked_name(subject: &str) -> Option<&str> {
+    let (_before, quoted) = subject.split_once('`')?;
+    let name = quoted.strip_suffix('`')?;
+    base_path(name).rsplit("::").next()
+}
+
+// A path without generic arguments, which may themselves contain `::`.
+fn base_path(path: &str) -> &str {
+    let path = path.split('<').next().unwrap_or(path);
+    path.strip_suffix("::").unwrap_or(path)
+}
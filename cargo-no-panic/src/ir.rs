@@ -0,0 +1,484 @@
+// Just enough of a reader for the textual LLVM IR that rustc writes with
+// `--emit=llvm-ir`, to follow calls from one function to another.
+//
+// Every call is recorded along with where it can unwind to, and with its
+// source location as a list of frames: the function the call ended up in,
+// then each function that was inlined into it down to the one containing the
+// call. The frames come from debug info, so the IR should be compiled with at
+// least `-C debuginfo=line-tables-only`.
+//
+// rustc precedes each function, and each call of a Rust function, with a
+// comment giving its demangled name. Those are used for display.
+
+use std::collections::HashMap;
+use std::mem;
+
+pub struct Function {
+    pub symbol: String,
+    // Demangled, when rustc said.
+    pub name: Option<String>,
+    pub defined: bool,
+    // Which of several modules read together it is from.
+    pub module: usize,
+    pub noreturn: bool,
+    pub nounwind: bool,
+    pub calls: Vec<Call>,
+    // Successors of each basic block.
+    pub blocks: Vec<Vec<usize>>,
+}
+
+pub struct Call {
+    pub callee: String,
+    pub name: Option<String>,
+    pub block: usize,
+    // The block it unwinds to, for an `invoke`.
+    pub unwind: Option<usize>,
+    pub noreturn: bool,
+    pub nounwind: bool,
+    // Outermost first.
+    pub frames: Vec<Frame>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Frame {
+    pub function: String,
+    pub file: String,
+    pub line: u32,
+    pub column: u32,
+}
+
+impl Function {
+    pub fn display_name(&self) -> &str {
+        self.name.as_deref().unwrap_or(&self.symbol)
+    }
+}
+
+// Functions defined or declared in one module, by symbol.
+pub fn parse(ir: &str) -> HashMap<String, Function> {
+    let mut parser = Parser::default();
+    for line in ir.lines() {
+        parser.line(line);
+    }
+    parser.finish()
+}
+
+#[derive(Default)]
+struct Parser<'a> {
+    functions: Vec<Function>,
+    // Attribute group references of each function and call, resolved once the
+    // groups at the end of the module are known.
+    function_attrs: Vec<Vec<&'a str>>,
+    call_attrs: Vec<Vec<Vec<&'a str>>>,
+    call_dbg: Vec<Vec<Option<&'a str>>>,
+    // Labels of the current function's blocks, and the labels each of its
+    // blocks and calls refer to.
+    labels: HashMap<&'a str, usize>,
+    successors: Vec<Vec<&'a str>>,
+    unwinds: Vec<Option<&'a str>>,
+    in_function: bool,
+    // Whether the previous line was the first of an `invoke` that was
+    // recorded, whose second line follows.
+    invoke: bool,
+    comment: Option<&'a str>,
+    groups: HashMap<&'a str, &'a str>,
+    metadata: HashMap<&'a str, &'a str>,
+}
+
+impl<'a> Parser<'a> {
+    fn line(&mut self, line: &'a str) {
+        if let Some(comment) = line.strip_prefix("; ") {
+            if !comment.starts_with("Function Attrs:") {
+                self.comment = Some(comment);
+            }
+            return;
+        }
+        if line.is_empty() {
+            self.comment = None;
+            return;
+        }
+        if self.in_function {
+            self.function_line(line);
+        } else if let Some(rest) = line.strip_prefix("define ") {
+            self.begin(rest, true);
+            self.in_function = true;
+        } else if let Some(rest) = line.strip_prefix("declare ") {
+            self.begin(rest, false);
+        } else if let Some(rest) = line.strip_prefix("attributes ") {
+            if let Some((id, group)) = rest.split_once(" = ") {
+                self.groups.insert(id, group);
+            }
+        } else if let Some((id, node)) = line.split_once(" = ") {
+            if id.starts_with('!') {
+                self.metadata.insert(id, node);
+            }
+        }
+    }
+
+    fn begin(&mut self, rest: &'a str, defined: bool) {
+        let Some((symbol, after)) = rest.find('@').and_then(|at| name(&rest[at + 1..])) else {
+            return;
+        };
+        let attrs = attribute_refs(skip_parens(after));
+        self.functions.push(Function {
+            symbol,
+            name: self.comment.take().map(str::to_owned),
+            defined,
+            module: 0,
+            noreturn: false,
+            nounwind: false,
+            calls: Vec::new(),
+            blocks: Vec::new(),
+        });
+        self.function_attrs.push(attrs);
+        self.call_attrs.push(Vec::new());
+        self.call_dbg.push(Vec::new());
+    }
+
+    fn function_line(&mut self, line: &'a str) {
+        let comment = self.comment.take();
+        let invoke = mem::take(&mut self.invoke);
+        if line == "}" {
+            self.end();
+            return;
+        }
+        let text = line.trim_start();
+        if text.len() == line.len() {
+            // A label, possibly quoted, maybe followed by `; preds = ...`.
+            let label = line.split(';').next().unwrap().trim_end();
+            if let Some(label) = label.strip_suffix(':') {
+                self.block(label.trim_matches('"'));
+            }
+            return;
+        }
+        if self.successors.is_empty() {
+            // The entry block has no label.
+            self.block("");
+        }
+        let block = self.successors.len() - 1;
+        for target in labels(text) {
+            self.successors[block].push(target);
+        }
+        if let Some(rest) = text.strip_prefix("to label ") {
+            // Second line of an `invoke`, with its debug location.
+            if !invoke {
+                return;
+            }
+            if let Some((_normal, unwind)) = rest.split_once(" unwind label ") {
+                *self.unwinds.last_mut().unwrap() = label_name(unwind);
+            }
+            let dbg = self.call_dbg.last_mut().unwrap();
+            if let Some(slot) = dbg.last_mut() {
+                *slot = dbg_ref(text);
+            }
+            return;
+        }
+        let Some((callee, after, is_invoke)) = callee(text) else {
+            return;
+        };
+        if callee.starts_with("llvm.") {
+            return;
+        }
+        self.invoke = is_invoke;
+        let name = comment.map(|comment| {
+            let comment = comment.strip_prefix("call ").unwrap_or(comment);
+            comment
+                .strip_prefix("invoke ")
+                .unwrap_or(comment)
+                .to_owned()
+        });
+        let function = self.functions.last_mut().unwrap();
+        function.calls.push(Call {
+            callee,
+            name,
+            block,
+            unwind: None,
+            noreturn: false,
+            nounwind: false,
+            frames: Vec::new(),
+        });
+        self.unwinds.push(None);
+        self.call_attrs
+            .last_mut()
+            .unwrap()
+            .push(attribute_refs(skip_parens(after)));
+        self.call_dbg.last_mut().unwrap().push(dbg_ref(after));
+    }
+
+    fn block(&mut self, label: &'a str) {
+        self.labels.insert(label, self.successors.len());
+        self.successors.push(Vec::new());
+    }
+
+    fn end(&mut self) {
+        self.in_function = false;
+        let function = self.functions.last_mut().unwrap();
+        function.blocks = self
+            .successors
+            .drain(..)
+            .map(|targets| {
+                targets
+                    .iter()
+                    .filter_map(|target| self.labels.get(target).copied())
+                    .collect()
+            })
+            .collect();
+        for (call, unwind) in function.calls.iter_mut().zip(self.unwinds.drain(..)) {
+            call.unwind = unwind.and_then(|label| self.labels.get(label).copied());
+        }
+        self.labels.clear();
+    }
+
+    fn finish(self) -> HashMap<String, Function> {
+        let has = |refs: &[&str], attr: &str| {
+            refs.iter().any(|id| {
+                self.groups
+                    .get(id)
+                    .is_some_and(|group| group.split_whitespace().any(|word| word == attr))
+            })
+        };
+        let debug_info = DebugInfo {
+            metadata: &self.metadata,
+        };
+        let mut functions = HashMap::new();
+        for (i, mut function) in self.functions.into_iter().enumerate() {
+            function.noreturn = has(&self.function_attrs[i], "noreturn");
+            function.nounwind = has(&self.function_attrs[i], "nounwind");
+            for (j, call) in function.calls.iter_mut().enumerate() {
+                call.noreturn = has(&self.call_attrs[i][j], "noreturn");
+                call.nounwind = has(&self.call_attrs[i][j], "nounwind");
+                if let Some(dbg) = self.call_dbg[i][j] {
+                    call.frames = debug_info.frames(dbg);
+                }
+            }
+            functions.insert(function.symbol.clone(), function);
+        }
+        functions
+    }
+}
+
+struct DebugInfo<'a> {
+    metadata: &'a HashMap<&'a str, &'a str>,
+}
+
+impl<'a> DebugInfo<'a> {
+    // `!DILocation(line: 9, column: 5, scope: !202, inlinedAt: !203)` and the
+    // locations it was inlined at.
+    fn frames(&self, mut id: &'a str) -> Vec<Frame> {
+        let mut frames = Vec::new();
+        while let Some(location) = self.node(id, "DILocation") {
+            let scope = field(location, "scope").unwrap_or_default();
+            let (function, file) = self.subprogram(scope);
+            frames.push(Frame {
+                function,
+                file,
+                line: field(location, "line")
+                    .and_then(|n| n.parse().ok())
+                    .unwrap_or(0),
+                column: field(location, "column")
+                    .and_then(|n| n.parse().ok())
+                    .unwrap_or(0),
+            });
+            match field(location, "inlinedAt") {
+                Some(inlined_at) => id = inlined_at,
+                None => break,
+            }
+        }
+        frames.reverse();
+        frames
+    }
+
+    // The qualified name and file of the function a scope is in.
+    fn subprogram(&self, mut id: &'a str) -> (String, String) {
+        for _ in 0..100 {
+            let Some(node) = self.metadata.get(id) else {
+                break;
+            };
+            let node = node.strip_prefix("distinct ").unwrap_or(node);
+            if node.starts_with("!DISubprogram(") {
+                let name = field(node, "name").unwrap_or_default();
+                let mut path = self.path(field(node, "scope"));
+                path.push(name.to_owned());
+                let file = self.file(field(node, "file"));
+                return (path.join("::"), file);
+            }
+            match field(node, "scope") {
+                Some(scope) => id = scope,
+                None => break,
+            }
+        }
+        (String::new(), String::new())
+    }
+
+    // Names of the namespaces and types enclosing a scope.
+    fn path(&self, mut scope: Option<&'a str>) -> Vec<String> {
+        let mut path = Vec::new();
+        while let Some(id) = scope {
+            let Some(node) = self.metadata.get(id) else {
+                break;
+            };
+            let node = node.strip_prefix("distinct ").unwrap_or(node);
+            if !node.starts_with("!DINamespace(") && !node.starts_with("!DICompositeType(") {
+                break;
+            }
+            path.extend(field(node, "name").map(str::to_owned));
+            scope = field(node, "scope");
+            if path.len() > 100 {
+                break;
+            }
+        }
+        path.reverse();
+        path
+    }
+
+    fn file(&self, id: Option<&'a str>) -> String {
+        id.and_then(|id| self.node(id, "DIFile"))
+            .and_then(|file| field(file, "filename"))
+            .unwrap_or_default()
+            .to_owned()
+    }
+
+    fn node(&self, id: &str, kind: &str) -> Option<&'a str> {
+        let node = self.metadata.get(id)?;
+        let node = node.strip_prefix("distinct ").unwrap_or(node);
+        let fields = node
+            .strip_prefix('!')?
+            .strip_prefix(kind)?
+            .strip_prefix('(')?;
+        Some(fields)
+    }
+}
+
+// The value of `key: value` in the fields of a metadata node, without quotes.
+fn field<'a>(node: &'a str, key: &str) -> Option<&'a str> {
+    let mut rest = node;
+    loop {
+        let at = rest.find(key)?;
+        let before = rest[..at].chars().next_back();
+        let after = &rest[at + key.len()..];
+        rest = after;
+        if !matches!(before, None | Some('(' | ' ')) {
+            continue;
+        }
+        let Some(value) = after.strip_prefix(": ") else {
+            continue;
+        };
+        if let Some(quoted) = value.strip_prefix('"') {
+            return quoted.split('"').next();
+        }
+        let end = value.find([',', ')']).unwrap_or(value.len());
+        return Some(&value[..end]);
+    }
+}
+
+// The symbol called by a `call` or `invoke` instruction, the rest of the line
+// after it, and whether it is an `invoke`, unless the call is indirect.
+fn callee(text: &str) -> Option<(String, &str, bool)> {
+    let (at, is_invoke) = [("call ", false), ("invoke ", true)]
+        .iter()
+        .filter_map(|&(keyword, is_invoke)| {
+            let at = if text.starts_with(keyword) {
+                0
+            } else {
+                text.find(&format!(" {}", keyword))?
+            };
+            Some((at, is_invoke))
+        })
+        .min()?;
+    let mut rest = &text[at..];
+    // The callee is the first name followed by its arguments.
+    while let Some(start) = rest.find(['@', '%']) {
+        let sigil = rest.as_bytes()[start];
+        let (symbol, after) = name(&rest[start + 1..])?;
+        if after.starts_with('(') {
+            return (sigil == b'@').then_some((symbol, after, is_invoke));
+        }
+        rest = after;
+    }
+    None
+}
+
+// A possibly quoted LLVM identifier at the start of `text`, unescaped, and
+// what follows it.
+fn name(text: &str) -> Option<(String, &str)> {
+    if let Some(quoted) = text.strip_prefix('"') {
+        let end = quoted.find('"')?;
+        return Some((unescape(&quoted[..end]), &quoted[end + 1..]));
+    }
+    let end = text
+        .find(|ch: char| !(ch.is_ascii_alphanumeric() || matches!(ch, '_' | '.' | '$' | '-')))
+        .unwrap_or(text.len());
+    if end == 0 {
+        return None;
+    }
+    Some((text[..end].to_owned(), &text[end..]))
+}
+
+// `\0A` is a newline in a quoted LLVM identifier.
+pub fn unescape(text: &str) -> String {
+    let mut bytes = Vec::new();
+    let mut rest = text.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        if byte == b'\\' {
+            if let Some(hex) = tail.get(..2) {
+                if let Ok(value) = u8::from_str_radix(&String::from_utf8_lossy(hex), 16) {
+                    bytes.push(value);
+                    rest = &tail[2..];
+                    continue;
+                }
+            }
+        }
+        bytes.push(byte);
+        rest = tail;
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+// Past the parenthesized parameter or argument list at the start of `text`.
+fn skip_parens(text: &str) -> &str {
+    let mut depth = 0;
+    let mut quoted = false;
+    for (i, ch) in text.char_indices() {
+        match ch {
+            '"' => quoted = !quoted,
+            '(' if !quoted => depth += 1,
+            ')' if !quoted => {
+                depth -= 1;
+                if depth == 0 {
+                    return &text[i + 1..];
+                }
+            }
+            _ => {}
+        }
+    }
+    ""
+}
+
+// `#5` attribute group references, up to the metadata attachments.
+fn attribute_refs(text: &str) -> Vec<&str> {
+    text.split(['!', '{'])
+        .next()
+        .unwrap_or_default()
+        .split([' ', ','])
+        .filter(|word| word.starts_with('#'))
+        .collect()
+}
+
+fn dbg_ref(text: &str) -> Option<&str> {
+    let (_before, after) = text.split_once("!dbg ")?;
+    let end = after.find([',', ' ']).unwrap_or(after.len());
+    Some(&after[..end])
+}
+
+// Targets of `label %name` in an instruction.
+fn labels(text: &str) -> impl Iterator<Item = &str> {
+    text.split("label %").skip(1).filter_map(label_name)
+}
+
+fn label_name(text: &str) -> Option<&str> {
+    let text = text.strip_prefix('%').unwrap_or(text);
+    if let Some(quoted) = text.strip_prefix('"') {
+        return quoted.split('"').next();
+    }
+    let end = text.find([',', ' ', ']']).unwrap_or(text.len());
+    (end > 0).then(|| &text[..end])
+}
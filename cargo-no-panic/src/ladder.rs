@@ -0,0 +1,180 @@
+// The ladder of build settings that the no-panic docs suggest trying in turn
+// to get a function proven panic-free, cheapest first. Each is applied to the
+// profile being built on top of its other settings.
+
+use crate::parse::Failure;
+use crate::source::Function;
+use std::path::{Path, PathBuf};
+
+pub struct Rung {
+    pub name: &'static str,
+    // Subdirectory of the target directory to build in, so that switching
+    // between rungs does not rebuild everything from scratch next time.
+    pub dir: &'static str,
+    // Values for `--config profile.<name>.<setting>`.
+    pub settings: &'static [&'static str],
+}
+
+pub const RUNGS: [Rung; 4] = [
+    Rung {
+        name: "opt-level = 1",
+        dir: "opt-level-1",
+        settings: &["opt-level=1", "lto=false"],
+    },
+    Rung {
+        name: "opt-level = 3",
+        dir: "opt-level-3",
+        settings: &["opt-level=3", "lto=false"],
+    },
+    Rung {
+        name: "opt-level = 3, lto = \"thin\"",
+        dir: "thin-lto",
+        settings: &["opt-level=3", "lto=\"thin\""],
+    },
+    Rung {
+        name: "opt-level = 3, lto = \"fat\", codegen-units = 1",
+        dir: "fat-lto",
+        settings: &["opt-level=3", "lto=\"fat\"", "codegen-units=1"],
+    },
+];
+
+// What the build with one rung found.
+pub struct Outcome {
+    pub failures: Vec<Failure>,
+    // Directories, relative to the workspace root, of packages that were not
+    // completely checked, because of some other error or a library that could
+    // not be scanned. Everything in them counts as failed.
+    pub unchecked: Vec<PathBuf>,
+}
+
+pub struct Entry {
+    pub subject: String,
+    pub location: String,
+    // Whether a verification build checks it on its own. Otherwise not
+    // failing proves nothing about it.
+    pub checked: bool,
+    // Whether it failed with each rung.
+    pub failed: Vec<bool>,
+}
+
+impl Entry {
+    // The index of the cheapest rung that proved it.
+    pub fn proven_by(&self) -> Option<usize> {
+        if !self.checked {
+            return None;
+        }
+        self.failed.iter().position(|failed| !failed)
+    }
+}
+
+// One entry per annotated function, with the failures of each rung's build
+// attributed to them. A failure of an instantiation or a statement counts for
+// the function it is in. Failures that no known function accounts for, such
+// as of a block, get entries of their own.
+pub fn entries(functions: &[Function], results: &[Outcome]) -> Vec<Entry> {
+    let mut entries: Vec<Entry> = functions
+        .iter()
+        .filter(|function| function.annotated)
+        .map(|function| Entry {
+            subject: format!("function `{}`", function.name),
+            location: function.location.clone(),
+            checked: function.standalone,
+            failed: results
+                .iter()
+                .map(|outcome| unchecked(outcome, &function.location))
+                .collect(),
+        })
+        .collect();
+    let known = entries.len();
+    for (rung, outcome) in results.iter().enumerate() {
+        for failure in &outcome.failures {
+            let location = failure.field("location").unwrap_or_default();
+            let index = entries[..known]
+                .iter()
+                .position(|entry| entry.location == location)
+                .or_else(|| {
+                    let name = failure.subject.strip_prefix("statement in ")?;
+                    let module = failure.field("module")?;
+                    functions
+                        .iter()
+                        .filter(|function| function.annotated)
+                        .position(|function| {
+                            function.module == module
+                                && name == format!("function `{}`", function.name)
+                        })
+                })
+                .or_else(|| {
+                    entries[known..]
+                        .iter()
+                        .position(|entry| {
+                            entry.subject == failure.subject && entry.location == location
+                        })
+                        .map(|i| known + i)
+                });
+            let index = index.unwrap_or_else(|| {
+                entries.push(Entry {
+                    subject: failure.subject.clone(),
+                    location: location.to_owned(),
+                    checked: true,
+                    failed: results
+                        .iter()
+                        .map(|outcome| unchecked(outcome, location))
+                        .collect(),
+                });
+                entries.len() - 1
+            });
+            entries[index].failed[rung] = true;
+        }
+    }
+    entries
+}
+
+fn unchecked(outcome: &Outcome, location: &str) -> bool {
+    let file = Path::new(location);
+    outcome.unchecked.iter().any(|dir| file.starts_with(dir))
+}
+
+// Group the entries by the rung that proved them. Returns whether all that
+// were checked were proven by some rung.
+pub fn print(entries: &[Entry]) -> bool {
+    for (index, rung) in RUNGS.iter().enumerate() {
+        let proven: Vec<&Entry> = entries
+            .iter()
+            .filter(|entry| entry.proven_by() == Some(index))
+            .collect();
+        if proven.is_empty() {
+            continue;
+        }
+        println!("proven with {}:", rung.name);
+        for entry in proven {
+            println!("    {} at {}", entry.subject, entry.location);
+        }
+    }
+    let unproven: Vec<&Entry> = entries
+        .iter()
+        .filter(|entry| entry.checked && entry.proven_by().is_none())
+        .collect();
+    if !unproven.is_empty() {
+        println!("not proven with any of them:");
+        for entry in &unproven {
+            println!("    {} at {}", entry.subject, entry.location);
+        }
+    }
+    let unchecked: Vec<&Entry> = entries.iter().filter(|entry| !entry.checked).collect();
+    if !unchecked.is_empty() {
+        println!("not checked, because a verification build does not check them on their own:");
+        for entry in &unchecked {
+            println!("    {} at {}", entry.subject, entry.location);
+        }
+    }
+    if let Some(cheapest) = entries.iter().filter_map(Entry::proven_by).max() {
+        let what = if unproven.is_empty() && unchecked.is_empty() {
+            "everything"
+        } else {
+            "everything else"
+        };
+        println!();
+        println!("{} is proven with {}", what, RUNGS[cheapest].name);
+    }
+    unproven.is_empty()
+}
@@ -0,0 +1,1107 @@
+//! Run Cargo and report the functions that [no-panic] could not prove
+//! panic-free as ordinary compiler errors, instead of as a linker failure.
+//!
+//! ```console
+//! $ cargo no-panic build --release
+//! error: detected panic in function `demo`
+//!  --> src/main.rs:4:1
+//!   |
+//! 4 | fn demo(s: &str) -> &str {
+//!   | ^^
+//!   |
+//!   = note: in module `no_panic_demo`
+//! ```
+//!
+//! `cargo no-panic verify` does the same, and also looks for surviving panic
+//! paths in the object code of library crates in the workspace, which Cargo
+//! does not otherwise link. It builds with `NO_PANIC_VERIFY=1`, so that
+//! annotated functions that nothing calls are checked too, and warns about the
+//! annotated functions that such a build does not check on their own, such as
+//! one annotated on its own without `verify`.
+//!
+//! `cargo no-panic explain` also shows how each failing function can panic: the
+//! shortest chain of calls from it to a panic, such as a call of
+//! `core::panicking::panic_bounds_check`, or to a function from another crate
+//! that was not inlined, with the source location of each call. It reads this
+//! from the LLVM IR of a build with line tables, kept apart from other builds
+//! in `target/no-panic/explain`. What the chain ends in tells the kind of
+//! panic, such as an index out of bounds or an `unwrap` of `None`, which comes
+//! with the usual way to avoid it. A call along the way that was not inlined is noted
+//! with the reason that LLVM gave for it, from `-C remark=inline`.
+//!
+//! ```console
+//! $ cargo no-panic explain --release
+//! error: detected panic in function `demo`
+//!   --> src/main.rs:13:1
+//!    |
+//! 13 | fn demo(v: &[u32], i: usize) -> u32 {
+//!    | ^^
+//!    |
+//!    = note: in module `ex`
+//!    = note: cause: index out of bounds
+//!    = note: can panic through this call chain:
+//!            ex::demo at src/main.rs:14:13
+//!            ex::middle at src/main.rs:9:5 (inlined)
+//!            ex::helper at src/main.rs:5:5
+//!            core::panicking::panic_bounds_check
+//!    = note: `ex::helper` was not inlined at src/main.rs:9:5 because it is `#[inline(never)]`
+//!    = help: index with `get()`, which returns an `Option`, or check the index against the length where the optimizer can see it
+//! ```
+//!
+//! A failure in a generic function notes the crate that instantiated it, and
+//! with `RUSTFLAGS="-C symbol-mangling-version=v0"` also the generic arguments,
+//! such as ``instantiated with `T = u8` in crate `down` ``.
+//!
+//! `cargo no-panic profiles` builds with each of the settings that are worth
+//! trying in turn, from `opt-level = 1` up to fat LTO with a single codegen
+//! unit, and groups the annotated functions by the cheapest one that proves
+//! them panic-free, so that you know which to put in the profile.
+//!
+//! `cargo no-panic suggest` tries the attribute on every function of the
+//! workspace that does not have it, in a release build of a copy of the
+//! workspace, and lists the ones that it proves panic-free. With `--write` it
+//! also annotates them the way they were tried: the impl block of a method,
+//! with `skip` on its methods that were not proven, and a free function with
+//! `verify`. Only functions that a verification build checks on their own are
+//! tried, leaving out generic and async functions, methods of generic impls and
+//! trait default methods, as well as code behind `#[cfg]`, which might not be
+//! compiled. Packages that do not depend on no-panic are skipped.
+//!
+//! `cargo no-panic coverage` lists the public functions of each package of the
+//! workspace with whether they are annotated, and whether a verification build
+//! checks them on their own, then the percentage that are annotated. It exits
+//! with an error if that is below `min-coverage` from the package's
+//! `[package.metadata.no-panic]`.
+//!
+//! Failures that are known and accepted for now can be listed in
+//! `no-panic.baseline` at the root of the workspace, which `cargo no-panic
+//! verify --write-baseline` writes. They are not reported, and the attribute,
+//! told about them through `NO_PANIC_BASELINE`, builds them as in abort mode,
+//! so that a binary that calls one still links. `cargo no-panic verify` also fails for an entry whose
+//! function no longer fails, which it tells by the message of its abort being
+//! gone from the object code, so that the entry gets removed.
+//!
+//! [no-panic]: https://github.com/dtolnay/no-panic
+
+#![allow(
+    clippy::cast_precision_loss,
+    clippy::too_many_lines,
+    clippy::uninlined_format_args
+)]
+
+mod artifact;
+mod baseline;
+mod cause;
+mod coverage;
+mod explain;
+mod ir;
+mod ladder;
+mod name;
+mod parse;
+mod remark;
+mod render;
+mod source;
+mod suggest;
+#[cfg(test)]
+mod tests;
+
+use crate::baseline::Baseline;
+use crate::parse::Failure;
+use clap::{Parser, Subcommand};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::env;
+use std::ffi::OsString;
+use std::fs;
+use std::io::{self, BufRead, BufReader};
+use std::path::{Component, Path, PathBuf};
+use std::process::{self, Command, Stdio};
+
+#[derive(Parser)]
+#[command(bin_name = "cargo", version, author, disable_help_subcommand = true)]
+enum Cargo {
+    #[command(name = "no-panic", version, author)]
+    NoPanic(NoPanic),
+}
+
+/// Report functions that no-panic could not prove panic-free
+#[derive(clap::Args)]
+struct NoPanic {
+    #[command(subcommand)]
+    command: Action,
+}
+
+#[derive(Subcommand)]
+enum Action {
+    /// Run `cargo build`, reporting each no-panic linker error as a diagnostic
+    Build {
+        /// Arguments for `cargo build`
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<OsString>,
+    },
+    /// Like `build`, and also check the object code of library crates in the
+    /// workspace, which otherwise nothing links
+    Verify {
+        /// Write every failure to no-panic.baseline, accepting them for now
+        #[arg(long)]
+        write_baseline: bool,
+        /// Arguments for `cargo build`
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<OsString>,
+    },
+    /// Like `build`, and also show the shortest call chain from each failing
+    /// function to a panic
+    Explain {
+        /// Arguments for `cargo build`
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<OsString>,
+    },
+    /// Build with each of opt-level 1, opt-level 3, thin LTO and fat LTO, and
+    /// report the cheapest that proves each annotated function panic-free
+    Profiles {
+        /// Arguments for `cargo build`
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<OsString>,
+    },
+    /// List the public functions of each package, and whether they are
+    /// annotated
+    Coverage {
+        /// Path to Cargo.toml
+        #[arg(long, value_name = "PATH")]
+        manifest_path: Option<OsString>,
+    },
+    /// Try no-panic on every function that is not annotated, and report the
+    /// ones it proves panic-free
+    Suggest {
+        /// Add `#[no_panic]` to those functions in the source files
+        #[arg(long)]
+        write: bool,
+        /// Arguments for `cargo build`
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<OsString>,
+    },
+}
+
+#[derive(Copy, Clone, PartialEq)]
+enum Check {
+    Build,
+    Verify,
+    Explain,
+}
+
+fn main() {
+    let Cargo::NoPanic(no_panic) = Cargo::parse();
+    let result = match no_panic.command {
+        Action::Build { args } => build(&args, Check::Build, false),
+        Action::Verify {
+            write_baseline,
+            args,
+        } => build(&args, Check::Verify, write_baseline),
+        Action::Explain { args } => build(&args, Check::Explain, false),
+        Action::Profiles { args } => profiles(&args),
+        Action::Suggest { write, args } => suggest(&args, write),
+        Action::Coverage { manifest_path } => coverage(manifest_path),
+    };
+    match result {
+        Ok(code) => process::exit(code),
+        Err(err) => {
+            eprintln!("error: {}", err);
+            process::exit(1);
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "reason", rename_all = "kebab-case")]
+enum Output {
+    CompilerMessage {
+        manifest_path: PathBuf,
+        message: Diagnostic,
+    },
+    CompilerArtifact {
+        package_id: String,
+        target: Target,
+        filenames: Vec<PathBuf>,
+        executable: Option<PathBuf>,
+    },
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Deserialize)]
+struct Diagnostic {
+    level: String,
+    rendered: Option<String>,
+}
+
+fn cargo() -> Command {
+    Command::new(env::var_os("CARGO").unwrap_or_else(|| OsString::from("cargo")))
+}
+
+fn build(args: &[OsString], check: Check, write_baseline: bool) -> io::Result<i32> {
+    let metadata = metadata(args)?;
+    let baseline = if write_baseline {
+        None
+    } else {
+        baseline::load(&metadata.workspace_root)?
+    };
+    // Only used to name methods in failures. A file that does not parse leaves
+    // them named as the errors have them.
+    let sources = workspace_functions(&metadata, |_package, _target| true).unwrap_or_default();
+
+    let mut command = cargo();
+    command.arg("build").arg("--message-format=json");
+    if write_baseline {
+        // A binary that fails to link must not stop the rest from being
+        // checked.
+        command.arg("--keep-going");
+    }
+    command.args(args);
+    if let Some(baseline) = &baseline {
+        // Listed failures are built as in abort mode, so that they link.
+        let keys = baseline.keys(&sources);
+        if !keys.is_empty() {
+            command.env("NO_PANIC_BASELINE", keys);
+        }
+    }
+    let mut ir_directory = None;
+    match check {
+        Check::Build => {}
+        Check::Verify => {
+            // Also check annotated functions that nothing calls.
+            command.env("NO_PANIC_VERIFY", "1");
+        }
+        Check::Explain => {
+            // Other flags than those of ordinary builds would make Cargo
+            // rebuild everything each time, unless built somewhere else.
+            let target_directory = metadata.target_directory.join("no-panic").join("explain");
+            add_rustflags(
+                &mut command,
+                &[
+                    "--emit=llvm-ir",
+                    "-Cdebuginfo=line-tables-only",
+                    "-Cremark=inline",
+                ],
+            );
+            command.env("CARGO_TARGET_DIR", &target_directory);
+            ir_directory = Some(target_directory);
+        }
+    }
+    let mut child = command.stdout(Stdio::piped()).spawn()?;
+
+    let mut failures = Vec::new();
+    // Failures from the baseline, found as data in what was built.
+    let mut listed: Vec<Failure> = Vec::new();
+    let mut remarks = Vec::new();
+    // Crates of the workspace that were checked, and whether any error other
+    // than from no-panic was seen, including a library that could not be
+    // checked.
+    let mut checked = Vec::new();
+    let mut errors = false;
+    let stdout = BufReader::new(child.stdout.take().unwrap());
+    for line in stdout.lines() {
+        let line = line?;
+        match serde_json::from_str(&line) {
+            Ok(Output::CompilerMessage {
+                manifest_path,
+                message,
+            }) => {
+                let Some(rendered) = message.rendered else {
+                    continue;
+                };
+                if ir_directory.is_some() && message.level == "note" {
+                    // Keep only the calls that were not inlined. A remark may
+                    // quote the symbol of a no-panic error, so these must not
+                    // be taken for failures.
+                    if let Some(remark) = remark::parse(&rendered) {
+                        if remark.reason.is_some() {
+                            remarks.push(remark);
+                        }
+                        continue;
+                    }
+                }
+                let found = parse::failures(&rendered);
+                if found.is_empty() {
+                    // Pass through every other diagnostic unchanged.
+                    eprint!("{}", rendered);
+                    errors |= message.level == "error" && !rendered.starts_with("error: aborting");
+                } else {
+                    let root = workspace_root(&manifest_path);
+                    let print = ir_directory.is_none() && !write_baseline;
+                    report(
+                        found,
+                        &root,
+                        &sources,
+                        &mut failures,
+                        baseline.as_ref(),
+                        print,
+                    );
+                }
+            }
+            Ok(Output::CompilerArtifact {
+                package_id,
+                target,
+                filenames,
+                executable,
+            }) => {
+                if check != Check::Verify || !metadata.workspace_members.contains(&package_id) {
+                    continue;
+                }
+                let mut complete = true;
+                for path in &filenames {
+                    let library = artifact::is_library(path);
+                    if !library && executable.as_ref() != Some(path) {
+                        continue;
+                    }
+                    let found = if library {
+                        artifact::failures(path)
+                    } else {
+                        Ok(Vec::new())
+                    };
+                    let found = found.and_then(|found| Ok((found, artifact::messages(path)?)));
+                    let (found, messages) = match found {
+                        Ok(found) => found,
+                        Err(err) => {
+                            eprintln!("error: could not check {}: {}\n", path.display(), err);
+                            errors = true;
+                            complete = false;
+                            continue;
+                        }
+                    };
+                    let root = &metadata.workspace_root;
+                    let print = !write_baseline;
+                    report(
+                        found,
+                        root,
+                        &sources,
+                        &mut failures,
+                        baseline.as_ref(),
+                        print,
+                    );
+                    if let Some(baseline) = &baseline {
+                        for mut message in messages {
+                            parse::qualify(&mut message, &sources);
+                            if baseline.contains(&message) && !listed.contains(&message) {
+                                listed.push(message);
+                            }
+                        }
+                    }
+                }
+                if complete {
+                    checked.push(target.name.replace('-', "_"));
+                }
+            }
+            Ok(Output::Other) | Err(_) => {}
+        }
+    }
+
+    let status = child.wait()?;
+    if check == Check::Verify {
+        print_unchecked(&sources);
+    }
+    if let Some(ir_directory) = &ir_directory {
+        let functions = if failures.is_empty() {
+            HashMap::new()
+        } else {
+            explain::load(ir_directory)?
+        };
+        for (failure, root) in &failures {
+            if baseline
+                .as_ref()
+                .is_some_and(|baseline| baseline.contains(failure))
+            {
+                continue;
+            }
+            let mut chain = explain::chain(failure, &functions);
+            if let Some(chain) = &mut chain {
+                remark::attach(chain, &remarks);
+            }
+            render::print(failure, root, chain.as_ref());
+        }
+    }
+    if write_baseline {
+        let failures: Vec<&Failure> = failures.iter().map(|(failure, _root)| failure).collect();
+        let content = baseline::render(&failures, &sources);
+        fs::write(metadata.workspace_root.join(baseline::FILE), &content)?;
+        // The same item may have failed both in a binary and in a library.
+        let n = content.lines().count();
+        eprintln!(
+            "wrote {} item{} to {}",
+            n,
+            if n == 1 { "" } else { "s" },
+            baseline::FILE,
+        );
+        return Ok(i32::from(errors));
+    }
+
+    let (mut expected, failures): (Vec<&Failure>, Vec<&Failure>) = failures
+        .iter()
+        .map(|(failure, _root)| failure)
+        .partition(|failure| baseline.as_ref().is_some_and(|b| b.contains(failure)));
+    expected.extend(&listed);
+    let stale = match &baseline {
+        // Only a verification build checks every annotated function, and
+        // only if everything was built, since a listed failure is found in
+        // what was built.
+        Some(baseline) if check == Check::Verify && status.success() => {
+            baseline.stale(&expected, &checked)
+        }
+        _ => Vec::new(),
+    };
+    for entry in &stale {
+        eprintln!(
+            "error: listed in {} but no longer fails: {}\n  = help: remove it from {}, so that it stays panic-free\n",
+            baseline::FILE,
+            entry,
+            baseline::FILE,
+        );
+    }
+    if failures.is_empty() && stale.is_empty() {
+        if status.success() {
+            return Ok(i32::from(errors));
+        }
+        if !expected.is_empty() && !errors {
+            // Only for a listed function that the source does not have, so
+            // that it could not be built as in abort mode.
+            eprintln!(
+                "note: linking failed only on items listed in {}",
+                baseline::FILE,
+            );
+        }
+        return Ok(status.code().unwrap_or(1));
+    }
+    if !failures.is_empty() {
+        let n = failures.len();
+        eprintln!(
+            "error: could not prove {} item{} panic-free",
+            n,
+            if n == 1 { "" } else { "s" },
+        );
+    }
+    if !stale.is_empty() {
+        let n = stale.len();
+        eprintln!(
+            "error: {} item{} in {} no longer fail{}",
+            n,
+            if n == 1 { "" } else { "s" },
+            baseline::FILE,
+            if n == 1 { "s" } else { "" },
+        );
+    }
+    Ok(1)
+}
+
+// Annotated functions that a verification build does not check on their own,
+// such as one annotated on its own without `verify`, which passing says
+// nothing about unless something calls them.
+fn print_unchecked(sources: &[source::Function]) {
+    let unchecked: Vec<&source::Function> = sources
+        .iter()
+        .filter(|function| function.annotated && !function.standalone)
+        .collect();
+    if unchecked.is_empty() {
+        return;
+    }
+    let n = unchecked.len();
+    eprintln!(
+        "warning: {} annotated function{} not checked on {} own, only as far as something calls {}:",
+        n,
+        if n == 1 { " is" } else { "s are" },
+        if n == 1 { "its" } else { "their" },
+        if n == 1 { "it" } else { "them" },
+    );
+    for function in &unchecked {
+        eprintln!("    function `{}` at {}", function.name, function.location);
+    }
+    eprintln!("  = help: add `verify` to a free function, annotate the impl block of a method instead, or give a generic function `instantiate`\n");
+}
+
+// Print each failure not seen before, with methods named by their type from
+// `sources`, unless printing is left until the IR has been read. Failures
+// listed in the baseline are not printed.
+fn report(
+    found: Vec<Failure>,
+    root: &Path,
+    sources: &[source::Function],
+    failures: &mut Vec<(Failure, PathBuf)>,
+    baseline: Option<&Baseline>,
+    print: bool,
+) {
+    for mut failure in found {
+        parse::qualify(&mut failure, sources);
+        if failures.iter().all(|(seen, _root)| *seen != failure) {
+            let expected = baseline.is_some_and(|baseline| baseline.contains(&failure));
+            if print && !expected {
+                render::print(&failure, root, None);
+            }
+            failures.push((failure, root.to_owned()));
+        }
+    }
+}
+
+fn profiles(args: &[OsString]) -> io::Result<i32> {
+    let metadata = metadata(args)?;
+    let mut functions = workspace_functions(&metadata, |_package, _target| true)?;
+    functions.retain(|function| function.annotated);
+
+    // Adjust whichever profile the arguments select.
+    let profile = profile(args);
+    let mut results = Vec::new();
+    for rung in &ladder::RUNGS {
+        eprintln!("{:>12} with {}", "Checking", rung.name);
+        let mut command = cargo();
+        command
+            .arg("build")
+            .arg("--message-format=json")
+            .arg("--keep-going")
+            .args(args);
+        if profile.is_none() {
+            command.arg("--release");
+        }
+        let name = profile.as_deref().unwrap_or("release");
+        for setting in rung.settings {
+            command
+                .arg("--config")
+                .arg(format!("profile.{}.{}", name, setting));
+        }
+        // Also check annotated functions that nothing calls.
+        command.env("NO_PANIC_VERIFY", "1");
+        let target_directory = metadata.target_directory.join("no-panic").join(rung.dir);
+        command.env("CARGO_TARGET_DIR", target_directory);
+        let build = quiet_build(command, &metadata, &functions)?;
+        if !build.success && build.failures.is_empty() {
+            return Ok(1);
+        }
+        let unchecked = build
+            .broken
+            .iter()
+            .filter_map(|manifest| {
+                manifest
+                    .parent()?
+                    .strip_prefix(&metadata.workspace_root)
+                    .ok()
+            })
+            .map(Path::to_owned)
+            .collect();
+        results.push(ladder::Outcome {
+            failures: build.failures,
+            unchecked,
+        });
+    }
+
+    let entries = ladder::entries(&functions, &results);
+    let all_proven = ladder::print(&entries);
+    Ok(i32::from(!all_proven))
+}
+
+fn suggest(args: &[OsString], write: bool) -> io::Result<i32> {
+    let metadata = metadata(args)?;
+    // The attribute is added as `no_panic::no_panic`, which needs no-panic as
+    // a dependency under its own name.
+    let uses_no_panic = |package: &Package| {
+        package.dependencies.iter().any(|dependency| {
+            dependency.name == "no-panic"
+                && dependency.rename.is_none()
+                && dependency.kind.is_none()
+        })
+    };
+    for package in &metadata.packages {
+        if !uses_no_panic(package) {
+            eprintln!(
+                "{:>12} {}, which does not depend on no-panic",
+                "Skipping", package.name,
+            );
+        }
+    }
+    let functions = workspace_functions(&metadata, |package, _target| uses_no_panic(package))?;
+    let tried = functions
+        .iter()
+        .filter(|function| function.candidate.as_ref().is_some_and(|c| c.eligible))
+        .count();
+    if tried == 0 {
+        println!("no functions to try");
+        return Ok(0);
+    }
+
+    // Build a copy of the workspace, so that the real one is left alone. It is
+    // placed at the same absolute path under the scratch directory, beside
+    // copies of the path dependencies outside of it, so that a dependency on
+    // `../x` still finds it.
+    let root = &metadata.workspace_root;
+    let directory = metadata.target_directory.join("no-panic").join("suggest");
+    let mirror = directory.join("workspace");
+    if mirror.exists() {
+        fs::remove_dir_all(&mirror)?;
+    }
+    for package in outside_packages(args, root)? {
+        copy_dir(
+            &package,
+            &mirrored(&package, &mirror),
+            &metadata.target_directory,
+        )?;
+    }
+    let copy = mirrored(root, &mirror);
+    copy_dir(root, &copy, &metadata.target_directory)?;
+    for (file, insertions) in suggest::trial(&functions) {
+        let path = relocate(&file, root, &copy);
+        let content = fs::read_to_string(&path)?;
+        fs::write(&path, suggest::apply(&content, &insertions))?;
+    }
+    let manifest = relocate(&root.join("Cargo.toml"), root, &copy);
+    let copy_metadata = crate::metadata(&[OsString::from("--manifest-path"), manifest.into()])?;
+
+    eprintln!("{:>12} {} functions", "Trying", tried);
+    let mut command = cargo();
+    command
+        .arg("build")
+        .arg("--message-format=json")
+        .arg("--keep-going")
+        .args(relocate_args(args, root, &copy));
+    if profile(args).is_none() {
+        command.arg("--release");
+    }
+    if let Ok(dir) = env::current_dir() {
+        if dir.starts_with(root) {
+            command.current_dir(relocate(&dir, root, &copy));
+        }
+    }
+    // Also check annotated functions that nothing calls.
+    command.env("NO_PANIC_VERIFY", "1");
+    command.env("CARGO_TARGET_DIR", directory.join("target"));
+    let build = quiet_build(command, &copy_metadata, &functions)?;
+
+    let mut failed = vec![false; functions.len()];
+    for failure in &build.failures {
+        if let Some(i) = failure.field("id").and_then(suggest::index) {
+            failed[i] = true;
+        }
+    }
+    let broken: Vec<PathBuf> = metadata
+        .packages
+        .iter()
+        .filter(|package| {
+            let manifest = relocate(&package.manifest_path, root, &copy);
+            build.broken.contains(&manifest)
+        })
+        .map(|package| package.manifest_path.clone())
+        .collect();
+    let passed: Vec<bool> = functions
+        .iter()
+        .enumerate()
+        .map(|(i, function)| {
+            let Some(candidate) = &function.candidate else {
+                return false;
+            };
+            let in_broken = broken.iter().any(|manifest| {
+                manifest
+                    .parent()
+                    .is_some_and(|dir| candidate.file.starts_with(dir))
+            });
+            candidate.eligible && !failed[i] && !in_broken
+        })
+        .collect();
+    let proven: Vec<&source::Function> = functions
+        .iter()
+        .zip(&passed)
+        .filter(|(_function, passed)| **passed)
+        .map(|(function, _passed)| function)
+        .collect();
+
+    if !proven.is_empty() {
+        println!("panic-free but not annotated:");
+        for function in &proven {
+            println!("    function `{}` at {}", function.name, function.location);
+        }
+        println!();
+    }
+    println!(
+        "{} of {} functions tried {} panic-free",
+        proven.len(),
+        tried,
+        if proven.len() == 1 { "is" } else { "are" },
+    );
+    if write {
+        for (file, insertions) in suggest::annotations(&functions, &passed) {
+            let content = fs::read_to_string(&file)?;
+            fs::write(&file, suggest::apply(&content, &insertions))?;
+        }
+        if !proven.is_empty() {
+            println!("added #[no_panic] to each of them");
+        }
+    }
+    if build.broken.is_empty() {
+        Ok(0)
+    } else {
+        eprintln!("error: functions of packages that failed to build were not tried");
+        Ok(1)
+    }
+}
+
+// Directories of the packages from a path outside of the workspace root.
+fn outside_packages(args: &[OsString], root: &Path) -> io::Result<Vec<PathBuf>> {
+    let metadata = cargo_metadata(args, false)?;
+    let directories = metadata
+        .packages
+        .into_iter()
+        .filter(|package| package.source.is_none())
+        .filter_map(|package| Some(package.manifest_path.parent()?.to_owned()))
+        .filter(|directory| !directory.starts_with(root))
+        .collect();
+    Ok(directories)
+}
+
+// Where an absolute path goes under `base`: `/a/b` is `base/a/b`.
+fn mirrored(path: &Path, base: &Path) -> PathBuf {
+    let mut mirrored = base.to_owned();
+    for component in path.components() {
+        match component {
+            Component::Prefix(prefix) => {
+                mirrored.push(prefix.as_os_str().to_string_lossy().replace(':', ""));
+            }
+            Component::Normal(part) => mirrored.push(part),
+            Component::RootDir | Component::CurDir | Component::ParentDir => {}
+        }
+    }
+    mirrored
+}
+
+// Copy a directory, other than version control and Cargo's target
+// directories, following symlinks.
+fn copy_dir(from: &Path, to: &Path, target_directory: &Path) -> io::Result<()> {
+    fs::create_dir_all(to)?;
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let path = entry.path();
+        if path == target_directory
+            || entry.file_name() == ".git"
+            || path.join("CACHEDIR.TAG").exists()
+        {
+            continue;
+        }
+        let destination = to.join(entry.file_name());
+        let metadata = fs::metadata(&path)?;
+        if metadata.is_dir() {
+            copy_dir(&path, &destination, target_directory)?;
+        } else if metadata.is_file() {
+            fs::copy(&path, &destination)?;
+        }
+    }
+    Ok(())
+}
+
+// The same path under another root.
+fn relocate(path: &Path, from: &Path, to: &Path) -> PathBuf {
+    match path.strip_prefix(from) {
+        Ok(relative) => to.join(relative),
+        Err(_) => path.to_owned(),
+    }
+}
+
+// Arguments for cargo build, with `--manifest-path` pointing into the copy.
+fn relocate_args(args: &[OsString], from: &Path, to: &Path) -> Vec<OsString> {
+    let mut relocated = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if arg == "--manifest-path" {
+            relocated.push(arg.clone());
+            if let Some(value) = args.next() {
+                let path = env::current_dir().unwrap_or_default().join(value);
+                relocated.push(relocate(&path, from, to).into());
+            }
+        } else if let Some(value) = arg
+            .to_str()
+            .and_then(|arg| arg.strip_prefix("--manifest-path="))
+        {
+            let path = env::current_dir().unwrap_or_default().join(value);
+            let mut arg = OsString::from("--manifest-path=");
+            arg.push(relocate(&path, from, to));
+            relocated.push(arg);
+        } else {
+            relocated.push(arg.clone());
+        }
+    }
+    relocated
+}
+
+fn coverage(manifest_path: Option<OsString>) -> io::Result<i32> {
+    let mut args = Vec::new();
+    if let Some(manifest_path) = manifest_path {
+        args.push(OsString::from("--manifest-path"));
+        args.push(manifest_path);
+    }
+    let metadata = metadata(&args)?;
+    let mut all_met = true;
+    for package in &metadata.packages {
+        // Only a library has a public API.
+        let functions = workspace_functions(&metadata, |candidate, target| {
+            candidate.manifest_path == package.manifest_path
+                && !target.kind.iter().any(|kind| kind == "bin")
+        })?;
+        let public: Vec<&source::Function> = functions
+            .iter()
+            .filter(|function| function.public)
+            .collect();
+        if public.is_empty() {
+            continue;
+        }
+        let threshold = coverage::threshold(package.metadata.as_ref());
+        all_met &= coverage::print(&package.name, &public, threshold);
+    }
+    Ok(i32::from(!all_met))
+}
+
+// The functions of the workspace's crates that end up linked into something,
+// which only those can be proven to be, from the targets selected.
+fn workspace_functions(
+    metadata: &Metadata,
+    select: impl Fn(&Package, &Target) -> bool,
+) -> io::Result<Vec<source::Function>> {
+    let mut functions: Vec<source::Function> = Vec::new();
+    for package in &metadata.packages {
+        for target in &package.targets {
+            if !select(package, target) {
+                continue;
+            }
+            let linked = target.kind.iter().all(|kind| {
+                matches!(
+                    kind.as_str(),
+                    "bin" | "lib" | "rlib" | "dylib" | "cdylib" | "staticlib",
+                )
+            });
+            if !linked {
+                continue;
+            }
+            let crate_name = target.name.replace('-', "_");
+            let root = &metadata.workspace_root;
+            for function in source::functions(&crate_name, &target.src_path, root)? {
+                if functions
+                    .iter()
+                    .all(|seen| seen.location != function.location)
+                {
+                    functions.push(function);
+                }
+            }
+        }
+    }
+    Ok(functions)
+}
+
+struct Build {
+    success: bool,
+    failures: Vec<Failure>,
+    // Manifests of the packages with errors other than those of no-panic,
+    // including a library that could not be checked.
+    broken: Vec<PathBuf>,
+}
+
+// Run a build, passing through only errors other than those of no-panic, and
+// collect the no-panic errors, including those in the object code of library
+// crates in the workspace, with methods named by their type from `functions`.
+fn quiet_build(
+    mut command: Command,
+    workspace: &Metadata,
+    functions: &[source::Function],
+) -> io::Result<Build> {
+    let mut child = command.stdout(Stdio::piped()).spawn()?;
+    let mut build = Build {
+        success: false,
+        failures: Vec::new(),
+        broken: Vec::new(),
+    };
+    let stdout = BufReader::new(child.stdout.take().unwrap());
+    for line in stdout.lines() {
+        let found = match serde_json::from_str(&line?) {
+            Ok(Output::CompilerMessage {
+                manifest_path,
+                message,
+            }) => {
+                let Some(rendered) = message.rendered else {
+                    continue;
+                };
+                let found = parse::failures(&rendered);
+                if found.is_empty() {
+                    if message.level == "error" {
+                        eprint!("{}", rendered);
+                        if !build.broken.contains(&manifest_path) {
+                            build.broken.push(manifest_path);
+                        }
+                    }
+                    continue;
+                }
+                found
+            }
+            Ok(Output::CompilerArtifact {
+                package_id,
+                target: _,
+                filenames,
+                executable: _,
+            }) => {
+                if !workspace.workspace_members.contains(&package_id) {
+                    continue;
+                }
+                let mut found = Vec::new();
+                for path in &filenames {
+                    if !artifact::is_library(path) {
+                        continue;
+                    }
+                    match artifact::failures(path) {
+                        Ok(failures) => found.extend(failures),
+                        Err(err) => {
+                            eprintln!("error: could not check {}: {}\n", path.display(), err);
+                            let package = workspace.packages.iter().find(|p| p.id == package_id);
+                            if let Some(package) = package {
+                                if !build.broken.contains(&package.manifest_path) {
+                                    build.broken.push(package.manifest_path.clone());
+                                }
+                            }
+                        }
+                    }
+                }
+                found
+            }
+            Ok(Output::Other) | Err(_) => continue,
+        };
+        for mut failure in found {
+            parse::qualify(&mut failure, functions);
+            if !build.failures.contains(&failure) {
+                build.failures.push(failure);
+            }
+        }
+    }
+    build.success = child.wait()?.success();
+    Ok(build)
+}
+
+#[derive(Deserialize)]
+struct Metadata {
+    workspace_root: PathBuf,
+    workspace_members: Vec<String>,
+    target_directory: PathBuf,
+    packages: Vec<Package>,
+}
+
+#[derive(Deserialize)]
+struct Package {
+    id: String,
+    name: String,
+    // None for a package of the workspace or from a path.
+    source: Option<String>,
+    manifest_path: PathBuf,
+    dependencies: Vec<Dependency>,
+    targets: Vec<Target>,
+    metadata: Option<serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+struct Dependency {
+    name: String,
+    rename: Option<String>,
+    kind: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Target {
+    name: String,
+    kind: Vec<String>,
+    src_path: PathBuf,
+}
+
+fn metadata(args: &[OsString]) -> io::Result<Metadata> {
+    cargo_metadata(args, true)
+}
+
+fn cargo_metadata(args: &[OsString], no_deps: bool) -> io::Result<Metadata> {
+    let mut command = cargo();
+    command.arg("metadata").arg("--format-version=1");
+    if no_deps {
+        command.arg("--no-deps");
+    }
+    if let Some(manifest_path) = manifest_path(args) {
+        command.arg("--manifest-path").arg(manifest_path);
+    }
+    let output = command.stderr(Stdio::inherit()).output()?;
+    if !output.status.success() {
+        process::exit(output.status.code().unwrap_or(1));
+    }
+    serde_json::from_slice(&output.stdout).map_err(io::Error::other)
+}
+
+// Pass more flags to rustc, in addition to those from the environment. Flags
+// from Cargo config files are dropped by Cargo in that case.
+fn add_rustflags(command: &mut Command, flags: &[&str]) {
+    if let Ok(mut encoded) = env::var("CARGO_ENCODED_RUSTFLAGS") {
+        for flag in flags {
+            if !encoded.is_empty() {
+                encoded.push('\x1f');
+            }
+            encoded.push_str(flag);
+        }
+        command.env("CARGO_ENCODED_RUSTFLAGS", encoded);
+    } else {
+        let mut rustflags = env::var("RUSTFLAGS").unwrap_or_default();
+        for flag in flags {
+            rustflags.push(' ');
+            rustflags.push_str(flag);
+        }
+        command.env("RUSTFLAGS", rustflags.trim_start());
+    }
+}
+
+// The value of `--profile` among the arguments for cargo build, or `release`
+// for `--release`.
+fn profile(args: &[OsString]) -> Option<String> {
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if arg == "--release" || arg == "-r" {
+            return Some("release".to_owned());
+        }
+        if arg == "--profile" {
+            return args
+                .next()
+                .map(|value| value.to_string_lossy().into_owned());
+        }
+        if let Some(value) = arg.to_str().and_then(|arg| arg.strip_prefix("--profile=")) {
+            return Some(value.to_owned());
+        }
+    }
+    None
+}
+
+// The value of `--manifest-path` among the arguments for cargo build.
+fn manifest_path(args: &[OsString]) -> Option<OsString> {
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if arg == "--manifest-path" {
+            return args.next().cloned();
+        }
+        if let Some(value) = arg
+            .to_str()
+            .and_then(|arg| arg.strip_prefix("--manifest-path="))
+        {
+            return Some(OsString::from(value));
+        }
+    }
+    None
+}
+
+// Cargo runs rustc from the workspace root, so that is where paths produced
+// by `file!()` are relative to.
+fn workspace_root(manifest_path: &Path) -> PathBuf {
+    let output = cargo()
+        .arg("locate-project")
+        .arg("--workspace")
+        .arg("--message-format=plain")
+        .arg("--manifest-path")
+        .arg(manifest_path)
+        .stderr(Stdio::null())
+        .output();
+    if let Ok(output) = output {
+        if output.status.success() {
+            let workspace_manifest = String::from_utf8_lossy(&output.stdout);
+            if let Some(root) = Path::new(workspace_manifest.trim_end()).parent() {
+                return root.to_owned();
+            }
+        }
+    }
+    manifest_path.parent().unwrap_or(Path::new(".")).to_owned()
+}
@@ -0,0 +1 @@
+../../src/name.rs
\ No newline at end of file
@@ -0,0 +1,308 @@
+// Pick the no-panic errors out of linker output.
+//
+// The undefined symbol generated by no-panic reads like:
+//
+//     ERROR[no-panic]: detected panic in function `demo`
+//       module: demo_crate::decode
+//       location: src/decode.rs:27:5
+//
+// GNU ld, gold, lld and mold all print the symbol verbatim inside of their own
+// "undefined reference" or "undefined symbol" message, and rustc indents the
+// linker's output. So a failure starts on the line containing the marker, and
+// its fields are the lines right after it that are indented further and look
+// like `key: value`.
+//
+// The linkers also say which object file and function refer to the symbol:
+//
+//     ld:    /path/to/x.o: in function `down::main':
+//            x-cgu.0:(.text+0x28): undefined reference to `
+//     gold:  /path/to/x.o:x-cgu.0:function down::main: error: undefined reference to '
+//     lld:   >>>               /path/to/x.o:(down::main::h36552180165498e5)
+//
+// ld and gold say so before the symbol, lld and mold after it.
+
+use crate::source::Function;
+
+const MARKER: &str = "ERROR[no-panic]: detected panic in ";
+
+#[derive(Debug, PartialEq)]
+pub struct Failure {
+    // What was checked: ``function `demo` ``, ``statement in function `demo` ``,
+    // `block` or `closure`.
+    pub subject: String,
+    pub fields: Vec<(String, String)>,
+    pub references: Vec<Reference>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Reference {
+    // Object file, possibly as `archive.rlib(member.o)`.
+    pub object: String,
+    // Function in it that refers to the symbol, as printed by the linker.
+    pub function: Option<String>,
+}
+
+impl Failure {
+    pub fn field(&self, key: &str) -> Option<&str> {
+        let (_key, value) = self.fields.iter().find(|(k, _value)| k == key)?;
+        Some(value)
+    }
+}
+
+impl Reference {
+    // The function that refers to the symbol, without the hash of a legacy
+    // symbol: `loc::checksum::sum`. For the drop of a guard that was not
+    // inlined, the item that the guard is in instead: `up::later::{closure#0}`
+    // for `<up::later::{closure#0}::__NoPanic<u32, 3> as core::ops::drop::Drop>::drop`.
+    pub fn path(&self) -> Option<String> {
+        let function = unescape_legacy(self.function.as_deref()?);
+        let path = match function.split_once("::__NoPanic") {
+            Some((path, _guard)) => {
+                // `_<` in a legacy symbol that the linker did not demangle.
+                let path = path.strip_prefix('_').unwrap_or(path);
+                path.strip_prefix('<').unwrap_or(path)
+            }
+            None => strip_hash(&function),
+        };
+        Some(path.to_owned())
+    }
+}
+
+// The error names a method annotated on its own by just its name, since the
+// attribute cannot see the impl it is in. Name it `Type::method` instead, or
+// `<Type as Trait>::method`, the way the source names the function at the same
+// location, or else the way the function that refers to the error is named, if
+// that is the method itself rather than a caller it was inlined into.
+pub fn qualify(failure: &mut Failure, functions: &[Function]) {
+    let Some((kind, quoted)) = failure.subject.split_once('`') else {
+        return;
+    };
+    let Some(name) = quoted.strip_suffix('`') else {
+        return;
+    };
+    // `nth::<u8>` for an instantiation.
+    let (ident, generics) = name.split_at(name.find("::<").unwrap_or(name.len()));
+    if ident.contains("::") {
+        return;
+    }
+    let module = failure.field("module").unwrap_or_default();
+    let location = failure.field("location").unwrap_or_default();
+    let qualified = match functions
+        .iter()
+        .find(|function| function.module == module && function.location == location)
+    {
+        Some(function) => function.name.clone(),
+        None => match failure
+            .references
+            .iter()
+            .find_map(|reference| method(&reference.path()?, module, ident))
+        {
+            // A function with a function nested in it looks the same.
+            Some(qualified)
+                if !functions.iter().any(|function| {
+                    function.module == module
+                        && qualified.starts_with(&format!("{}::", function.name))
+                }) =>
+            {
+                qualified
+            }
+            _ => return,
+        },
+    };
+    if qualified != ident && qualified.ends_with(&format!("::{}", ident)) {
+        failure.subject = format!("{}`{}{}`", kind, qualified, generics);
+    }
+}
+
+// `Type::method` from the path of a method in `module`: `loc::Reader::get`,
+// `<loc::Reader<u8>>::get` or `<loc::Reader as loc::Peek>::get`, also with
+// closures of its body after it.
+fn method(path: &str, module: &str, ident: &str) -> Option<String> {
+    let mut path = path;
+    while let Some((parent, last)) = path.rsplit_once("::") {
+        if !last.starts_with('{') {
+            break;
+        }
+        path = parent;
+    }
+    let prefix = path.strip_suffix(ident)?.strip_suffix("::")?;
+    let ty = if let Some(qualified) = prefix.strip_prefix('<').and_then(|ty| ty.strip_suffix('>')) {
+        if let Some((ty, trait_)) = qualified.split_once(" as ") {
+            let (ty, trait_) = (last_segment(ty), last_segment(trait_));
+            return Some(format!("<{} as {}>::{}", ty, trait_, ident));
+        }
+        last_segment(qualified)
+    } else {
+        let ty = prefix.strip_prefix(module)?.strip_prefix("::")?;
+        let ty = ty.split('<').next().unwrap_or(ty);
+        if ty.is_empty() || ty.contains("::") {
+            return None;
+        }
+        ty
+    };
+    Some(format!("{}::{}", ty, ident))
+}
+
+// `Reader` for `loc::Reader<u8>`.
+fn last_segment(path: &str) -> &str {
+    let path = path.split('<').next().unwrap_or(path);
+    path.rsplit("::").next().unwrap_or(path)
+}
+
+// lld prints legacy symbols without demangling them when they are not valid
+// Itanium names, as in `_$LT$up..nth..__NoPanic$LT$T$GT$$u20$as$u20$...`.
+pub fn unescape_legacy(function: &str) -> String {
+    if !function.contains('$') {
+        return function.to_owned();
+    }
+    function
+        .replace("$LT$", "<")
+        .replace("$GT$", ">")
+        .replace("$u20$", " ")
+        .replace("$C$", ",")
+        .replace("..", "::")
+}
+
+// Without the hash at the end of a legacy symbol: `::h0f1e2d3c4b5a6978`.
+fn strip_hash(function: &str) -> &str {
+    match function.rsplit_once("::h") {
+        Some((path, hash)) if hash.len() == 16 && hash.chars().all(|ch| ch.is_ascii_hexdigit()) => {
+            path
+        }
+        _ => function,
+    }
+}
+
+// Every distinct failure in `output`, in order of first appearance. A linker
+// may report the same symbol once per reference to it.
+pub fn failures(output: &str) -> Vec<Failure> {
+    let mut failures: Vec<Failure> = Vec::new();
+    let mut lines = output.lines().peekable();
+    // The last `in function` printed by ld, and what gold printed on the line
+    // of the undefined reference.
+    let mut context = None;
+    let mut pending = None;
+    while let Some(line) = lines.next() {
+        let text = linker_text(line);
+        if let Some(reference) = ld_context(text) {
+            context = Some(reference);
+            continue;
+        }
+        if text.contains("undefined reference to ") {
+            pending = gold_reference(text).or_else(|| context.clone());
+            continue;
+        }
+        let Some(start) = line.find(MARKER) else {
+            continue;
+        };
+        let indent = indentation(line);
+        let subject = line[start + MARKER.len()..].trim_end().to_owned();
+        let mut fields = Vec::new();
+        while let Some(field) = lines.peek().and_then(|line| field(line, indent)) {
+            fields.push(field);
+            lines.next();
+        }
+        let mut references: Vec<Reference> = pending.take().into_iter().collect();
+        while let Some(line) = lines.peek() {
+            let text = line.trim();
+            if text.is_empty() || text == "'" {
+                lines.next();
+            } else if let Some(text) = text.strip_prefix(">>>") {
+                references.extend(lld_reference(text.trim()));
+                lines.next();
+            } else {
+                break;
+            }
+        }
+        match failures
+            .iter_mut()
+            .find(|failure| failure.subject == subject && failure.fields == fields)
+        {
+            Some(failure) => {
+                for reference in references {
+                    if !failure.references.contains(&reference) {
+                        failure.references.push(reference);
+                    }
+                }
+            }
+            None => failures.push(Failure {
+                subject,
+                fields,
+                references,
+            }),
+        }
+    }
+    failures
+}
+
+// The failures whose message is in `data`, such as a section of object code
+// that prints them at runtime. A message starts on a line of its own, so
+// whatever comes before and after it in the data does not get in the way.
+pub fn messages(data: &[u8]) -> Vec<Failure> {
+    failures(&String::from_utf8_lossy(data))
+}
+
+fn field(line: &str, indent: usize) -> Option<(String, String)> {
+    if indentation(line) <= indent {
+        return None;
+    }
+    let (key, value) = line.trim().split_once(':')?;
+    let is_key = !key.is_empty()
+        && key
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'_' || b == b'-');
+    if !is_key {
+        return None;
+    }
+    Some((key.to_owned(), value.trim_start().to_owned()))
+}
+
+fn indentation(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
+
+// A line of linker output without rustc's indentation and `= note: `.
+fn linker_text(line: &str) -> &str {
+    let line = line.trim();
+    line.strip_prefix("= note: ").unwrap_or(line).trim_start()
+}
+
+// `/usr/bin/ld: /path/to/x.o: in function `f':`
+fn ld_context(text: &str) -> Option<Reference> {
+    let (before, function) = text.split_once(": in function `")?;
+    let function = function.strip_suffix("':")?;
+    let object = before
+        .rsplit_once(": ")
+        .map_or(before, |(_ld, object)| object);
+    Some(Reference {
+        object: object.to_owned(),
+        function: Some(function.to_owned()),
+    })
+}
+
+// `/path/to/x.o:x-cgu.0:function f: error: undefined reference to '`
+fn gold_reference(text: &str) -> Option<Reference> {
+    let (before, _error) = text.split_once(": error: undefined reference to ")?;
+    let (object, rest) = before.split_once(':')?;
+    let function = rest
+        .split_once(":function ")
+        .map(|(_source, function)| function.to_owned());
+    Some(Reference {
+        object: object.to_owned(),
+        function,
+    })
+}
+
+// `/path/to/x.o:(f)`, after the `>>>`. The other lines lld and mold print
+// there name the source file, or count the remaining references.
+fn lld_reference(text: &str) -> Option<Reference> {
+    if text.starts_with("referenced ") {
+        return None;
+    }
+    let (object, function) = text.rsplit_once(":(")?;
+    let function = function.strip_suffix(')')?;
+    Some(Reference {
+        object: object.to_owned(),
+        function: Some(function.to_owned()),
+    })
+}
@@ -0,0 +1,136 @@
+// Why a call was not inlined, from the optimization remarks that rustc prints
+// as notes with `-C remark=inline`:
+//
+//     note: /path/to/src/main.rs:9:5 inline (missed): 'callee' not inlined into 'caller' because too costly to inline (cost=150, threshold=45)
+//     note: /path/to/src/main.rs:5:5 inline (missed): callee will not be inlined into caller because its definition is unavailable
+//
+// The callee and the caller it was or was not inlined into are symbols. The
+// location is that of the call, with the file as an absolute path.
+
+use crate::explain::{Chain, End};
+
+pub struct Remark {
+    pub file: String,
+    pub line: u32,
+    pub column: u32,
+    pub callee: String,
+    // None if the call was inlined.
+    pub reason: Option<Reason>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Reason {
+    // The callee is in another crate, and neither generic nor `#[inline]`, or
+    // is in another codegen unit of the same crate.
+    Unavailable,
+    NoInline,
+    Recursive,
+    TooCostly { cost: String, threshold: String },
+    Other(String),
+}
+
+// A remark of the inliner, whether or not the call was inlined.
+pub fn parse(rendered: &str) -> Option<Remark> {
+    let rest = rendered.strip_prefix("note: ")?;
+    let (location, rest) = rest.split_once(" inline (")?;
+    let mut pieces = location.rsplitn(3, ':');
+    let column = pieces.next()?.parse().ok()?;
+    let line = pieces.next()?.parse().ok()?;
+    let file = pieces.next()?.to_owned();
+    let (outcome, message) = rest.split_once("): ")?;
+    let message = message.trim_end();
+    let (callee, reason) = if outcome == "missed" {
+        let (call, because) = message.split_once(" because ")?;
+        let (callee, _caller) = call
+            .split_once(" will not be inlined into ")
+            .or_else(|| call.split_once(" not inlined into "))?;
+        (callee, Some(Reason::parse(because)))
+    } else {
+        let (callee, _rest) = message.split_once(" inlined into ")?;
+        (callee, None)
+    };
+    Some(Remark {
+        file,
+        line,
+        column,
+        callee: callee.trim_matches('\'').to_owned(),
+        reason,
+    })
+}
+
+impl Reason {
+    fn parse(because: &str) -> Self {
+        if because == "its definition is unavailable" {
+            return Reason::Unavailable;
+        }
+        if let Some((_never, why)) = because.split_once("(cost=never): ") {
+            match why {
+                "noinline function attribute" => return Reason::NoInline,
+                "recursive" | "recursive call" => return Reason::Recursive,
+                _ => {}
+            }
+        }
+        if let Some(numbers) = because
+            .strip_prefix("too costly to inline (cost=")
+            .and_then(|rest| rest.strip_suffix(')'))
+        {
+            if let Some((cost, threshold)) = numbers.split_once(", threshold=") {
+                return Reason::TooCostly {
+                    cost: cost.to_owned(),
+                    threshold: threshold.to_owned(),
+                };
+            }
+        }
+        Reason::Other(because.to_owned())
+    }
+
+    pub fn description(&self) -> String {
+        match self {
+            Reason::Unavailable => {
+                "its body is in another crate or codegen unit, and it is not `#[inline]`".to_owned()
+            }
+            Reason::NoInline => "it is `#[inline(never)]`".to_owned(),
+            Reason::Recursive => "it is recursive".to_owned(),
+            Reason::TooCostly { cost, threshold } => format!(
+                "it is too big to inline there (cost {}, threshold {})",
+                cost, threshold,
+            ),
+            Reason::Other(because) => because.clone(),
+        }
+    }
+}
+
+// Give each call in the chain that was not inlined the reason for it. A call
+// of the panic entry point at the end is left out, since those never are.
+pub fn attach(chain: &mut Chain, remarks: &[Remark]) {
+    let last = chain.steps.len().saturating_sub(1);
+    let ends_in_panic = matches!(chain.end, End::Panic(_));
+    for (i, step) in chain.steps.iter_mut().enumerate() {
+        if i == last && ends_in_panic {
+            continue;
+        }
+        let Some(callee) = &step.callee else {
+            continue;
+        };
+        step.reason = remarks
+            .iter()
+            .filter(|remark| remark.callee == *callee && remark.is_at(&step.location))
+            .find_map(|remark| remark.reason.clone());
+    }
+}
+
+impl Remark {
+    // Whether it is about a call at `file:line:column`, where the file is as
+    // given in debug info, usually relative to the directory rustc ran in.
+    fn is_at(&self, location: &str) -> bool {
+        let expected = format!(":{}:{}", self.line, self.column);
+        let Some(file) = location.strip_suffix(&expected) else {
+            return false;
+        };
+        self.file == file
+            || self
+                .file
+                .strip_suffix(file)
+                .is_some_and(|dir| dir.ends_with('/') || dir.ends_with('\\'))
+    }
+}
@@ -0,0 +1,282 @@
+use crate::cause::Cause;
+use crate::explain::{Chain, End};
+use crate::parse::{self, Failure};
+use std::fs;
+use std::path::Path;
+
+// Print a failure the way rustc prints an error, pointing at the annotated
+// item. `root` is the directory that paths from `file!()` are relative to.
+pub fn print(failure: &Failure, root: &Path, chain: Option<&Chain>) {
+    eprintln!("error: detected panic in {}", failure.subject);
+    let location = failure.field("location").and_then(Location::parse);
+    let mut gutter = String::new();
+    if let Some(location) = &location {
+        let snippet = location.snippet(root);
+        if snippet.is_some() {
+            gutter = " ".repeat(location.line.to_string().len());
+        }
+        eprintln!(
+            "{}--> {}:{}:{}",
+            gutter, location.file, location.line, location.column,
+        );
+        if let Some((source, underline)) = snippet {
+            eprintln!("{} |", gutter);
+            eprintln!("{} | {}", location.line, source);
+            eprintln!("{} | {}", gutter, underline);
+            eprintln!("{} |", gutter);
+        }
+    }
+    for (key, value) in &failure.fields {
+        match key.as_str() {
+            "location" if location.is_some() => {}
+            "generics" if !failure.references.is_empty() => {}
+            "module" => eprintln!("{} = note: in module `{}`", gutter, value),
+            _ => eprintln!("{} = note: {}: {}", gutter, key, value),
+        }
+    }
+    if matches!(failure.subject.as_str(), "block" | "closure") {
+        let module = failure.field("module").unwrap_or_default();
+        let mut functions = Vec::new();
+        for reference in &failure.references {
+            let path = reference.path();
+            if let Some(function) = path.and_then(|path| enclosing(&path, module)) {
+                if !functions.contains(&function) {
+                    functions.push(function);
+                }
+            }
+        }
+        for function in functions {
+            eprintln!("{} = note: in function `{}`", gutter, function);
+        }
+    }
+    if let Some(names) = failure.field("generics") {
+        instantiations(failure, names, &gutter);
+    }
+    if let Some(chain) = chain {
+        call_chain(chain, &gutter);
+    }
+    eprintln!();
+}
+
+// One line per function from the checked one to the panic, each aligned with
+// the text of the note, why calls along the way were not inlined, and what to
+// do about the kind of panic it ends in.
+fn call_chain(chain: &Chain, gutter: &str) {
+    let cause = Cause::of(chain);
+    if let Some(cause) = cause {
+        eprintln!("{} = note: cause: {}", gutter, cause.description());
+    }
+    eprintln!("{} = note: can panic through this call chain:", gutter);
+    for step in &chain.steps {
+        eprintln!(
+            "{}         {} at {}{}",
+            gutter,
+            step.function,
+            step.location,
+            if step.inlined { " (inlined)" } else { "" },
+        );
+    }
+    match &chain.end {
+        End::Panic(function) => eprintln!("{}         {}", gutter, function),
+        End::Opaque(function) => eprintln!(
+            "{}         {} (from another crate, not inlined)",
+            gutter, function,
+        ),
+    }
+    for (i, step) in chain.steps.iter().enumerate() {
+        let Some(reason) = &step.reason else {
+            continue;
+        };
+        let callee = match chain.steps.get(i + 1) {
+            Some(next) => &next.function,
+            None => match &chain.end {
+                End::Panic(function) | End::Opaque(function) => function,
+            },
+        };
+        eprintln!(
+            "{} = note: `{}` was not inlined at {} because {}",
+            gutter,
+            callee,
+            step.location,
+            reason.description(),
+        );
+    }
+    if chain.others > 0 {
+        eprintln!(
+            "{} = note: {} other call{} in it can panic as well",
+            gutter,
+            chain.others,
+            if chain.others == 1 { "" } else { "s" },
+        );
+    }
+    if let Some(cause) = cause {
+        eprintln!("{} = help: {}", gutter, cause.help());
+    }
+}
+
+// Which generic arguments each reference to the guard of a generic function
+// was instantiated with, and by which crate. The arguments come from the name
+// of the guard's drop impl, `<up::nth::__NoPanic<u8> as Drop>::drop`, which
+// only has them under v0 symbol mangling. Legacy mangling leaves the names of
+// the parameters in their place.
+fn instantiations(failure: &Failure, names: &str, gutter: &str) {
+    let names: Vec<&str> = names.split(", ").collect();
+    let mut notes = Vec::new();
+    let mut mangled = false;
+    for reference in &failure.references {
+        let krate = crate_name(&reference.object);
+        let arguments = reference.function.as_deref().and_then(guard_arguments);
+        let note = match arguments {
+            Some(arguments)
+                if arguments.len() == names.len() && is_concrete(&names, &arguments) =>
+            {
+                let bindings: Vec<String> = names
+                    .iter()
+                    .zip(&arguments)
+                    .map(|(name, argument)| format!("{} = {}", name, argument))
+                    .collect();
+                format!(
+                    "instantiated with `{}` in crate `{}`",
+                    bindings.join(", "),
+                    krate,
+                )
+            }
+            arguments => {
+                mangled |= arguments.is_some();
+                format!("instantiated in crate `{}`", krate)
+            }
+        };
+        if !notes.contains(&note) {
+            notes.push(note);
+        }
+    }
+    for note in notes {
+        eprintln!("{} = note: {}", gutter, note);
+    }
+    if mangled {
+        eprintln!(
+            "{} = help: build with RUSTFLAGS=\"-C symbol-mangling-version=v0\" to see the generic arguments",
+            gutter,
+        );
+    }
+}
+
+// The generic arguments of `__NoPanic` in the name of a function.
+fn guard_arguments(function: &str) -> Option<Vec<String>> {
+    let function = parse::unescape_legacy(function);
+    let (_path, rest) = function.split_once("__NoPanic<")?;
+    let mut arguments = Vec::new();
+    let mut argument = String::new();
+    let mut depth = 0;
+    let mut prev = ' ';
+    for ch in rest.chars() {
+        match ch {
+            '<' | '(' | '[' => depth += 1,
+            '>' if prev == '-' => {}
+            '>' | ')' | ']' if depth == 0 => {
+                arguments.push(argument.trim().to_owned());
+                return Some(arguments);
+            }
+            '>' | ')' | ']' => depth -= 1,
+            ',' if depth == 0 => {
+                arguments.push(argument.trim().to_owned());
+                argument.clear();
+                prev = ch;
+                continue;
+            }
+            _ => {}
+        }
+        argument.push(ch);
+        prev = ch;
+    }
+    None
+}
+
+// Whether the arguments are types and values, rather than the names of the
+// parameters as printed from a legacy symbol: `__NoPanic<T, _>`.
+fn is_concrete(names: &[&str], arguments: &[String]) -> bool {
+    !names
+        .iter()
+        .zip(arguments)
+        .all(|(name, argument)| argument == name || argument == "_" || argument == "__Self")
+}
+
+// The function that a block or closure is in, relative to `module`, from the
+// path of the function that refers to its guard. If the enclosing function
+// was inlined into another one, this finds the other one, which is only used
+// if it is in the same module.
+pub fn enclosing(path: &str, module: &str) -> Option<String> {
+    let mut path = path.to_owned();
+    // `<up::Type>::method`, under v0 mangling.
+    if let Some((ty, rest)) = path
+        .strip_prefix('<')
+        .and_then(|path| path.split_once(">::"))
+    {
+        if !ty.contains(" as ") {
+            path = format!("{}::{}", ty, rest);
+        }
+    }
+    let mut segments: Vec<&str> = path.split("::").collect();
+    while segments
+        .last()
+        .is_some_and(|segment| segment.starts_with('{'))
+    {
+        segments.pop();
+    }
+    let path = segments.join("::");
+    let name = path.strip_prefix(module)?.strip_prefix("::")?;
+    if name.is_empty() {
+        None
+    } else {
+        Some(name.to_owned())
+    }
+}
+
+// `/path/to/down-8ed7f248e7591a2f.down.9b99be9cec2ec9a9-cgu.0.rcgu.o` is an
+// object of crate `down`, and so is `libdown-8ed7f248e7591a2f.rlib(down.o)`.
+fn crate_name(object: &str) -> &str {
+    let mut file = object.rsplit('/').next().unwrap_or(object);
+    if let Some((_archive, member)) = file
+        .strip_suffix(')')
+        .and_then(|file| file.rsplit_once('('))
+    {
+        file = member;
+    }
+    let end = file.find(['-', '.']).unwrap_or(file.len());
+    &file[..end]
+}
+
+struct Location<'a> {
+    file: &'a str,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> Location<'a> {
+    fn parse(location: &'a str) -> Option<Self> {
+        let mut pieces = location.rsplitn(3, ':');
+        let column = pieces.next()?.parse().ok()?;
+        let line = pieces.next()?.parse().ok()?;
+        let file = pieces.next()?;
+        Some(Location { file, line, column })
+    }
+
+    // The source line, and carets under the token at the location.
+    fn snippet(&self, root: &Path) -> Option<(String, String)> {
+        let source = fs::read_to_string(root.join(self.file)).ok()?;
+        let line = source.lines().nth(self.line.checked_sub(1)?)?;
+        let column = self.column.checked_sub(1)?;
+        let padding: String = line
+            .chars()
+            .take(column)
+            .map(|ch| if ch == '\t' { '\t' } else { ' ' })
+            .collect();
+        let width = line
+            .chars()
+            .skip(column)
+            .take_while(|ch| ch.is_alphanumeric() || *ch == '_')
+            .count()
+            .max(1);
+        Some((line.to_owned(), format!("{}{}", padding, "^".repeat(width))))
+    }
+}
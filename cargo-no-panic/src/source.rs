@@ -0,0 +1,449 @@
+// Find the functions of a crate, and which of them are annotated, by reading
+// its source files with syn. Modules are followed from the crate root the way
+// rustc finds their files. Code behind `#[cfg]` is read regardless, and items
+// nested inside of function bodies or produced by macros are not seen.
+
+use crate::name::{path_name, type_name};
+use proc_macro2::{Ident, LineColumn, Span, TokenTree};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use syn::visit::Visit;
+use syn::{
+    Attribute, FnArg, GenericArgument, GenericParam, ImplItem, ImplItemFn, Item, ItemImpl, ItemMod,
+    Meta, PathArguments, ReturnType, Safety, Signature, TraitItem, Type, Visibility,
+};
+
+pub struct Function {
+    // As named in a no-panic error once qualified: `demo`, or `Type::method`
+    // and `<Type as Trait>::method` for a method.
+    pub name: String,
+    pub module: String,
+    // Of the `fn` token, like the error's `location`, with the file relative
+    // to the workspace root.
+    pub location: String,
+    pub annotated: bool,
+    // Whether a verification build checks it on its own, for an annotated
+    // function, rather than only as far as something calls it.
+    pub standalone: bool,
+    // Declared `pub`, in a module that is `pub` all the way up to the crate
+    // root. Methods of trait impls are not counted.
+    pub public: bool,
+    // Where `#[no_panic]` could be added, for a function that is not annotated
+    // and was not opted out with `skip`.
+    pub candidate: Option<Candidate>,
+}
+
+pub struct Candidate {
+    pub file: PathBuf,
+    // Of the first token after the function's attributes.
+    pub start: LineColumn,
+    // Of the first token after the attributes of the impl block, for a method.
+    // A verification build only checks a method that uses `self` on its own
+    // if the attribute is on its impl block.
+    pub impl_start: Option<LineColumn>,
+    // Whether a verification build would check it on its own once annotated,
+    // so that not failing means it is panic-free. Not so for code behind a
+    // `#[cfg]`, which might not be compiled, nor for generic functions, async
+    // functions, functions with `impl Trait` parameters and methods of generic
+    // impls.
+    pub eligible: bool,
+}
+
+pub fn functions(crate_name: &str, src_path: &Path, root: &Path) -> io::Result<Vec<Function>> {
+    let mut walker = Walker {
+        root,
+        functions: Vec::new(),
+    };
+    let dir = src_path.parent().unwrap_or(Path::new("."));
+    let inherited = Inherited {
+        annotated: false,
+        excluded: false,
+        public: true,
+    };
+    walker.file(src_path, crate_name, dir, inherited)?;
+    Ok(walker.functions)
+}
+
+struct Walker<'a> {
+    root: &'a Path,
+    functions: Vec<Function>,
+}
+
+// Where the items are, for resolving `mod m;` within them.
+struct Scope<'a> {
+    file: &'a Path,
+    module: &'a str,
+    // Directory of the files of child modules.
+    dir: &'a Path,
+    // Inside of an inline module of the file.
+    inline: bool,
+    inherited: Inherited,
+}
+
+// What the items of a module get from the modules around them.
+#[derive(Copy, Clone)]
+struct Inherited {
+    annotated: bool,
+    // Behind a `#[cfg]` or a `#[no_panic(skip)]`, where no annotations are
+    // suggested.
+    excluded: bool,
+    public: bool,
+}
+
+impl Walker<'_> {
+    fn file(
+        &mut self,
+        path: &Path,
+        module: &str,
+        dir: &Path,
+        inherited: Inherited,
+    ) -> io::Result<()> {
+        let content = fs::read_to_string(path)?;
+        let file = syn::parse_file(&content).map_err(|err| {
+            let message = format!("failed to parse {}: {}", path.display(), err);
+            io::Error::new(io::ErrorKind::InvalidData, message)
+        })?;
+        let scope = Scope {
+            file: path,
+            module,
+            dir,
+            inline: false,
+            inherited,
+        };
+        self.items(&file.items, &scope)
+    }
+
+    fn items(&mut self, items: &[Item], scope: &Scope) -> io::Result<()> {
+        let Inherited {
+            annotated,
+            excluded,
+            public,
+        } = scope.inherited;
+        for item in items {
+            match item {
+                Item::Fn(function) => {
+                    let own = annotation(&function.attrs);
+                    // One annotated on its own, rather than through its
+                    // module, is only checked on its own through a copy made
+                    // for `verify` or `instantiate`.
+                    let instantiate = has_arg(&function.attrs, "instantiate");
+                    let copied = instantiate || has_arg(&function.attrs, "verify");
+                    let retained = annotated || copied;
+                    let annotated = own.unwrap_or(annotated);
+                    let sig = &function.sig;
+                    self.functions.push(Function {
+                        name: sig.ident.to_string(),
+                        module: scope.module.to_owned(),
+                        location: self.location(scope, sig.fn_token.span),
+                        annotated,
+                        standalone: annotated && retained && checkable(sig, instantiate),
+                        public: public && matches!(function.vis, Visibility::Public(_)),
+                        candidate: (own.is_none() && !annotated).then(|| Candidate {
+                            file: scope.file.to_owned(),
+                            start: start(&function.vis, sig),
+                            impl_start: None,
+                            eligible: !excluded
+                                && !conditional(&function.attrs)
+                                && checkable(sig, false),
+                        }),
+                    });
+                }
+                Item::Impl(imp) => {
+                    let impl_own = annotation(&imp.attrs);
+                    let annotated_impl = impl_own.unwrap_or(annotated);
+                    // Methods of a generic impl are not checked on their own.
+                    let generic =
+                        !imp.generics.params.is_empty() || imp.generics.where_clause.is_some();
+                    let impl_eligible = !excluded && !conditional(&imp.attrs) && !generic;
+                    let self_name = type_name(&imp.self_ty);
+                    let prefix = match &imp.trait_ {
+                        Some((path, _for)) => format!("<{} as {}>", self_name, path_name(path)),
+                        None => self_name,
+                    };
+                    for item in &imp.items {
+                        let ImplItem::Fn(method) = item else {
+                            continue;
+                        };
+                        let own = annotation(&method.attrs);
+                        let sig = &method.sig;
+                        let instantiate = has_arg(&method.attrs, "instantiate");
+                        // A method annotated on its own is checked on its own
+                        // through a copy of it, which cannot use `self` and is
+                        // only made for `verify` or `instantiate`.
+                        let standalone = if own.is_some() && !annotated_impl {
+                            let copied = instantiate || has_arg(&method.attrs, "verify");
+                            let copyable = sig.receiver().is_none() && !mentions_self(method);
+                            copied && copyable && checkable(sig, instantiate)
+                        } else {
+                            !generic && checkable(sig, instantiate)
+                        };
+                        let name = format!("{}::{}", prefix, sig.ident);
+                        let annotated = own.unwrap_or(annotated_impl);
+                        let candidate = own.is_none() && impl_own.is_none() && !annotated;
+                        self.functions.push(Function {
+                            name,
+                            module: scope.module.to_owned(),
+                            location: self.location(scope, sig.fn_token.span),
+                            annotated,
+                            standalone: annotated && standalone,
+                            public: public
+                                && imp.trait_.is_none()
+                                && matches!(method.vis, Visibility::Public(_)),
+                            candidate: candidate.then(|| Candidate {
+                                file: scope.file.to_owned(),
+                                start: start(&method.vis, sig),
+                                impl_start: Some(impl_start(imp)),
+                                eligible: impl_eligible
+                                    && !conditional(&method.attrs)
+                                    && checkable(sig, false),
+                            }),
+                        });
+                    }
+                }
+                Item::Trait(trait_) => {
+                    let annotated_trait = annotation(&trait_.attrs).unwrap_or(annotated);
+                    for item in &trait_.items {
+                        let TraitItem::Fn(method) = item else {
+                            continue;
+                        };
+                        if method.default.is_none() {
+                            continue;
+                        }
+                        let own = annotation(&method.attrs);
+                        let name = format!("{}::{}", trait_.ident, method.sig.ident);
+                        // Default methods are not checked on their own.
+                        self.functions.push(Function {
+                            name,
+                            module: scope.module.to_owned(),
+                            location: self.location(scope, method.sig.fn_token.span),
+                            annotated: own.unwrap_or(annotated_trait),
+                            standalone: false,
+                            public: false,
+                            candidate: None,
+                        });
+                    }
+                }
+                Item::Mod(module) => self.module(module, scope)?,
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn module(&mut self, module: &ItemMod, scope: &Scope) -> io::Result<()> {
+        let own = annotation(&module.attrs);
+        let outer = scope.inherited;
+        let inherited = Inherited {
+            annotated: own.unwrap_or(outer.annotated),
+            excluded: outer.excluded || own == Some(false) || conditional(&module.attrs),
+            public: outer.public && matches!(module.vis, Visibility::Public(_)),
+        };
+        let name = module.ident.to_string();
+        let path = format!("{}::{}", scope.module, name);
+        if let Some((_brace, items)) = &module.content {
+            let dir = scope.dir.join(&name);
+            let inner = Scope {
+                file: scope.file,
+                module: &path,
+                dir: &dir,
+                inline: true,
+                inherited,
+            };
+            return self.items(items, &inner);
+        }
+        let file = if let Some(relative) = path_attr(&module.attrs) {
+            let base = if scope.inline {
+                scope.dir
+            } else {
+                scope.file.parent().unwrap_or(Path::new("."))
+            };
+            base.join(relative)
+        } else {
+            let file = scope.dir.join(format!("{}.rs", name));
+            if file.exists() {
+                file
+            } else {
+                scope.dir.join(&name).join("mod.rs")
+            }
+        };
+        if !file.exists() {
+            // Probably behind a cfg that does not apply here.
+            return Ok(());
+        }
+        let dir = module_dir(&file);
+        self.file(&file, &path, &dir, inherited)
+    }
+
+    fn location(&self, scope: &Scope, fn_token: Span) -> String {
+        let file = scope.file.strip_prefix(self.root).unwrap_or(scope.file);
+        let start = fn_token.start();
+        format!("{}:{}:{}", file.display(), start.line, start.column + 1)
+    }
+}
+
+// Whether a `#[no_panic]` among the attributes turns checking on or, with
+// `skip`, off.
+fn annotation(attrs: &[Attribute]) -> Option<bool> {
+    let attr = attrs.iter().find(|attr| {
+        let segments = &attr.path().segments;
+        segments.last().is_some_and(|last| last.ident == "no_panic")
+    })?;
+    let skip = match &attr.meta {
+        Meta::List(list) => list
+            .tokens
+            .clone()
+            .into_iter()
+            .any(|tt| matches!(tt, TokenTree::Ident(ident) if ident == "skip")),
+        Meta::Path(_) | Meta::NameValue(_) => false,
+    };
+    Some(!skip)
+}
+
+// Whether the `#[no_panic]` among the attributes has an argument `arg`, as in
+// `instantiate(...)` or `verify`.
+fn has_arg(attrs: &[Attribute], arg: &str) -> bool {
+    attrs.iter().any(|attr| match &attr.meta {
+        Meta::List(list)
+            if list
+                .path
+                .segments
+                .last()
+                .is_some_and(|last| last.ident == "no_panic") =>
+        {
+            list.tokens
+                .clone()
+                .into_iter()
+                .any(|tt| matches!(tt, TokenTree::Ident(ident) if ident == arg))
+        }
+        _ => false,
+    })
+}
+
+// Whether `Self` appears in the method, outside of macro invocations.
+fn mentions_self(method: &ImplItemFn) -> bool {
+    struct FindSelf(bool);
+
+    impl<'ast> Visit<'ast> for FindSelf {
+        fn visit_ident(&mut self, ident: &'ast Ident) {
+            self.0 |= ident == "Self";
+        }
+    }
+
+    let mut find = FindSelf(false);
+    find.visit_impl_item_fn(method);
+    find.0
+}
+
+// Whether the item is only compiled some of the time.
+fn conditional(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        let segments = &attr.path().segments;
+        segments.last().is_some_and(|last| {
+            last.ident == "cfg" || last.ident == "test" || last.ident == "bench"
+        })
+    })
+}
+
+// Whether a verification build can check a function with this signature on
+// its own: one that is not async, and generic over nothing but lifetimes
+// unless given arguments to `instantiate` it with, and not through `impl Trait`
+// parameters either.
+fn checkable(sig: &Signature, instantiate: bool) -> bool {
+    sig.asyncness.is_none()
+        && sig.variadic.is_none()
+        && (instantiate
+            || sig
+                .generics
+                .params
+                .iter()
+                .all(|param| matches!(param, GenericParam::Lifetime(_))))
+        && sig.inputs.iter().all(|input| match input {
+            FnArg::Typed(arg) => !has_impl_trait(&arg.ty),
+            FnArg::Receiver(_) => true,
+        })
+}
+
+fn has_impl_trait(ty: &Type) -> bool {
+    match ty {
+        Type::ImplTrait(_) => true,
+        Type::Array(ty) => has_impl_trait(&ty.elem),
+        Type::Group(ty) => has_impl_trait(&ty.elem),
+        Type::Paren(ty) => has_impl_trait(&ty.elem),
+        Type::Ptr(ty) => has_impl_trait(&ty.elem),
+        Type::Reference(ty) => has_impl_trait(&ty.elem),
+        Type::Slice(ty) => has_impl_trait(&ty.elem),
+        Type::Tuple(ty) => ty.elems.iter().any(has_impl_trait),
+        Type::Path(ty) => ty.path.segments.iter().any(|segment| match &segment.arguments {
+            PathArguments::None => false,
+            PathArguments::AngleBracketed(arguments) => {
+                arguments.args.iter().any(|argument| match argument {
+                    GenericArgument::Type(ty) => has_impl_trait(ty),
+                    _ => false,
+                })
+            }
+            PathArguments::Parenthesized(arguments) => {
+                arguments.inputs.iter().any(|input| has_impl_trait(&input.ty))
+                    || matches!(&arguments.output, ReturnType::Type(_, ty) if has_impl_trait(ty))
+            }
+        }),
+        _ => false,
+    }
+}
+
+// The first token of a function after its attributes.
+fn start(vis: &Visibility, sig: &Signature) -> LineColumn {
+    let span = match vis {
+        Visibility::Public(token) => token.span,
+        Visibility::Restricted(restricted) => restricted.pub_token.span,
+        Visibility::Inherited => {
+            if let Some(token) = &sig.constness {
+                token.span
+            } else if let Some(token) = &sig.asyncness {
+                token.span
+            } else if let Safety::Unsafe(token) = &sig.safety {
+                token.span
+            } else if let Safety::Safe(token) = &sig.safety {
+                token.span
+            } else if let Some(abi) = &sig.abi {
+                abi.extern_token.span
+            } else {
+                sig.fn_token.span
+            }
+        }
+    };
+    span.start()
+}
+
+fn impl_start(imp: &ItemImpl) -> LineColumn {
+    let span = match &imp.unsafety {
+        Some(token) => token.span,
+        None => imp.impl_token.span,
+    };
+    span.start()
+}
+
+fn path_attr(attrs: &[Attribute]) -> Option<String> {
+    attrs.iter().find_map(|attr| match &attr.meta {
+        Meta::NameValue(meta) if meta.path.is_ident("path") => match &meta.value {
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(path),
+                ..
+            }) => Some(path.value()),
+            _ => None,
+        },
+        _ => None,
+    })
+}
+
+// The directory of the child modules of a module in `file`.
+fn module_dir(file: &Path) -> PathBuf {
+    let parent = file.parent().unwrap_or(Path::new("."));
+    if file.file_name().is_some_and(|name| name == "mod.rs") {
+        return parent.to_owned();
+    }
+    match file.file_stem() {
+        Some(stem) => parent.join(stem),
+        None => parent.to_owned(),
+    }
+}
@@ -0,0 +1,175 @@
+// Try the attribute on every function that could have it, by building a copy
+// of the workspace in which each of them is annotated with an `id` of its
+// own. The ones whose id is in no failure are panic-free.
+//
+// A method that uses `self` is only checked on its own in a verification
+// build when its impl block is annotated. So is the impl block in the copy,
+// and any of its methods that are not being tried get `skip`. A free function
+// gets `verify`, without which it is not checked on its own either.
+
+use crate::source::Function;
+use proc_macro2::LineColumn;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+const ID_PREFIX: &str = "no-panic-suggest-";
+
+pub struct Insertion {
+    pub at: LineColumn,
+    pub text: String,
+}
+
+// Attributes to add to each file of the copy. `functions[i]` gets the id
+// of `i`.
+pub fn trial(functions: &[Function]) -> BTreeMap<PathBuf, Vec<Insertion>> {
+    let mut edits: BTreeMap<PathBuf, Vec<Insertion>> = BTreeMap::new();
+    let mut impls = Vec::new();
+    for (i, function) in functions.iter().enumerate() {
+        let Some(candidate) = function.candidate.as_ref().filter(|c| c.eligible) else {
+            continue;
+        };
+        let verify = if candidate.impl_start.is_none() {
+            "verify, "
+        } else {
+            ""
+        };
+        let text = format!(
+            "#[::no_panic::no_panic({}id = \"{}{}\")] ",
+            verify, ID_PREFIX, i,
+        );
+        edits
+            .entry(candidate.file.clone())
+            .or_default()
+            .push(Insertion {
+                at: candidate.start,
+                text,
+            });
+        if let Some(impl_start) = candidate.impl_start {
+            let key = (&candidate.file, impl_start.line, impl_start.column);
+            if !impls.contains(&key) {
+                impls.push(key);
+                edits
+                    .entry(candidate.file.clone())
+                    .or_default()
+                    .push(Insertion {
+                        at: impl_start,
+                        text: "#[::no_panic::no_panic] ".to_owned(),
+                    });
+            }
+        }
+    }
+    // Keep the rest of the methods of those impl blocks out of the check.
+    for function in functions {
+        let Some(candidate) = &function.candidate else {
+            continue;
+        };
+        let in_tried_impl = candidate.impl_start.is_some_and(|impl_start| {
+            impls.contains(&(&candidate.file, impl_start.line, impl_start.column))
+        });
+        if !candidate.eligible && in_tried_impl {
+            edits
+                .entry(candidate.file.clone())
+                .or_default()
+                .push(Insertion {
+                    at: candidate.start,
+                    text: "#[::no_panic::no_panic(skip)] ".to_owned(),
+                });
+        }
+    }
+    edits
+}
+
+// Which function a failure with this `id` is of.
+pub fn index(id: &str) -> Option<usize> {
+    id.strip_prefix(ID_PREFIX)?.parse().ok()
+}
+
+// The attributes to add for the functions that were proven, `passed[i]` for
+// `functions[i]`, each on a line of its own: the same as in the trial, so
+// that what gets checked is what was proven. That is the impl block of a
+// method, with `skip` on its methods that were not proven.
+pub fn annotations(functions: &[Function], passed: &[bool]) -> BTreeMap<PathBuf, Vec<Insertion>> {
+    let mut edits: BTreeMap<PathBuf, Vec<Insertion>> = BTreeMap::new();
+    let mut impls = Vec::new();
+    for (function, passed) in functions.iter().zip(passed) {
+        let Some(candidate) = function.candidate.as_ref().filter(|_| *passed) else {
+            continue;
+        };
+        let (at, text) = match candidate.impl_start {
+            Some(impl_start) => {
+                let key = (&candidate.file, impl_start.line, impl_start.column);
+                if impls.contains(&key) {
+                    continue;
+                }
+                impls.push(key);
+                (impl_start, "#[no_panic::no_panic]\n")
+            }
+            None => (candidate.start, "#[no_panic::no_panic(verify)]\n"),
+        };
+        edits
+            .entry(candidate.file.clone())
+            .or_default()
+            .push(Insertion {
+                at,
+                text: text.to_owned(),
+            });
+    }
+    for (function, passed) in functions.iter().zip(passed) {
+        let Some(candidate) = &function.candidate else {
+            continue;
+        };
+        let in_annotated_impl = candidate.impl_start.is_some_and(|impl_start| {
+            impls.contains(&(&candidate.file, impl_start.line, impl_start.column))
+        });
+        if !passed && in_annotated_impl {
+            edits
+                .entry(candidate.file.clone())
+                .or_default()
+                .push(Insertion {
+                    at: candidate.start,
+                    text: "#[no_panic::no_panic(skip)]\n".to_owned(),
+                });
+        }
+    }
+    edits
+}
+
+// Insert text at each position, given by a 1-based line and a column counted
+// in characters. Text that ends in a newline is followed by the indentation of
+// the line it is inserted into, unless something other than whitespace comes
+// before it on that line, in which case it gets a space instead of the
+// newline.
+pub fn apply(content: &str, insertions: &[Insertion]) -> String {
+    let mut offsets = Vec::new();
+    for insertion in insertions {
+        let Some(line) = content
+            .split_inclusive('\n')
+            .nth(insertion.at.line.saturating_sub(1))
+        else {
+            continue;
+        };
+        let line_start = line.as_ptr() as usize - content.as_ptr() as usize;
+        let column = line
+            .char_indices()
+            .nth(insertion.at.column)
+            .map_or(line.len(), |(i, _ch)| i);
+        let before = &line[..column];
+        let text = match insertion.text.strip_suffix('\n') {
+            Some(text) if before.trim().is_empty() => format!("{}\n{}", text, before),
+            Some(text) => format!("{} ", text),
+            None => insertion.text.clone(),
+        };
+        offsets.push((line_start + column, text));
+    }
+    // Insert at the same position in the given order.
+    offsets.sort_by_key(|(offset, _text)| *offset);
+    let mut result = String::with_capacity(content.len());
+    let mut copied = 0;
+    for (offset, text) in offsets {
+        result.push_str(&content[copied..offset]);
+        result.push_str(&text);
+        copied = offset;
+    }
+    result.push_str(&content[copied..]);
+    result
+}
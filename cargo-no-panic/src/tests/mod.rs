@@ -0,0 +1,40 @@
+// Fixtures shared by the tests of each module.
+
+mod test_baseline;
+mod test_coverage;
+mod test_explain;
+mod test_ladder;
+mod test_parse;
+mod test_remark;
+mod test_render;
+mod test_source;
+mod test_suggest;
+
+use crate::parse::Failure;
+use crate::source::Function;
+
+// A failure with only the fields that every failure has, and no references.
+fn failure(subject: &str, module: &str, location: &str) -> Failure {
+    Failure {
+        subject: subject.to_owned(),
+        fields: vec![
+            ("module".to_owned(), module.to_owned()),
+            ("location".to_owned(), location.to_owned()),
+        ],
+        references: Vec::new(),
+    }
+}
+
+// A function that is not annotated, not public and cannot be annotated, for
+// the rest to be filled in.
+fn function(name: &str, module: &str, location: &str) -> Function {
+    Function {
+        name: name.to_owned(),
+        module: module.to_owned(),
+        location: location.to_owned(),
+        annotated: false,
+        standalone: false,
+        public: false,
+        candidate: None,
+    }
+}
@@ -0,0 +1,113 @@
+use super::{failure, function};
+use crate::baseline::{self, Baseline};
+
+#[test]
+fn test_render() {
+    let read = failure(
+        "function `Reader::read`",
+        "demo::decode",
+        "src/decode.rs:27:5",
+    );
+    let skip = failure(
+        "statement in function `skip`",
+        "demo::decode",
+        "src/decode.rs:40:9",
+    );
+    let closure = failure("closure", "demo", "src/lib.rs:40:17");
+    // Two functions of the module are called `new`, and only one failed.
+    let new = failure("function `new`", "demo::decode", "src/decode.rs:12:9");
+    let functions = [
+        function("Reader::read", "demo::decode", "src/decode.rs:27:5"),
+        function("new", "demo::decode", "src/decode.rs:12:9"),
+        function("new", "demo::decode", "src/decode.rs:20:9"),
+    ];
+
+    let content = baseline::render(&[&skip, &read, &closure, &new, &read], &functions);
+    let expected = "\
+demo: closure at src/lib.rs:40:17
+demo::decode: function `Reader::read`
+demo::decode: function `new` at src/decode.rs:12:9
+demo::decode: statement in function `skip` at src/decode.rs:40:9
+";
+    assert_eq!(content, expected);
+
+    // Two instantiations of one function.
+    let nth_u8 = failure("function `nth::<u8>`", "demo", "src/lib.rs:3:1");
+    let nth_u16 = failure("function `nth::<u16>`", "demo", "src/lib.rs:3:1");
+    let content = baseline::render(&[&nth_u8, &nth_u16], &[]);
+    let expected = "\
+demo: function `nth::<u16>`
+demo: function `nth::<u8>`
+";
+    assert_eq!(content, expected);
+}
+
+#[test]
+fn test_stale() {
+    let baseline = Baseline::parse(
+        "\
+# Known to panic, until the decoder checks its lengths.
+demo::decode: function `Reader::read`
+demo::decode: function `Reader::peek`
+demo::decode: function `new` at src/decode.rs:12:9
+
+util: function `split`
+",
+    );
+    let read = failure(
+        "function `Reader::read`",
+        "demo::decode",
+        "src/decode.rs:27:5",
+    );
+    let moved = failure(
+        "function `Reader::read`",
+        "demo::decode",
+        "src/decode.rs:31:5",
+    );
+    let next = failure(
+        "function `Reader::next`",
+        "demo::decode",
+        "src/decode.rs:50:5",
+    );
+    assert!(baseline.contains(&read));
+    assert!(baseline.contains(&moved));
+    assert!(!baseline.contains(&next));
+
+    // Functions that share a name are listed by location.
+    let decoder_new = failure("function `new`", "demo::decode", "src/decode.rs:12:9");
+    let reader_new = failure("function `new`", "demo::decode", "src/decode.rs:20:9");
+    assert!(baseline.contains(&decoder_new));
+    assert!(!baseline.contains(&reader_new));
+
+    // `util` was not built, so its entry cannot be known to be stale.
+    let checked = ["demo".to_owned()];
+    let stale = baseline.stale(&[&read, &decoder_new], &checked);
+    assert_eq!(stale, ["demo::decode: function `Reader::peek`"]);
+}
+
+#[test]
+fn test_keys() {
+    let baseline = Baseline::parse(
+        "\
+demo: closure at src/lib.rs:40:17
+demo: function `nth::<u8>`
+demo::decode: function `Reader::read`
+demo::decode: function `gone`
+demo::decode: function `new` at src/decode.rs:12:9
+demo::decode: statement in function `skip` at src/decode.rs:40:9
+",
+    );
+    let functions = [
+        function("nth", "demo", "src/lib.rs:3:1"),
+        function("Reader::read", "demo::decode", "src/decode.rs:27:5"),
+        function("read", "demo::decode", "src/decode.rs:60:1"),
+    ];
+    let expected = "\
+demo src/lib.rs:40:17
+demo src/lib.rs:3:1::<u8>
+demo::decode src/decode.rs:27:5
+demo::decode src/decode.rs:12:9
+demo::decode src/decode.rs:40:9
+";
+    assert_eq!(baseline.keys(&functions), expected);
+}
@@ -0,0 +1,46 @@
+use crate::coverage::{self, Status};
+use crate::source::{self, Function};
+use std::path::Path;
+
+#[test]
+fn test_coverage() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/source");
+    let src_path = root.join("src/lib.rs");
+    let functions = source::functions("source", &src_path, &root).unwrap();
+    let statuses: Vec<(&str, Status)> = functions
+        .iter()
+        .filter(|function| function.annotated)
+        .map(|function| (function.name.as_str(), Status::of(function)))
+        .collect();
+    assert_eq!(
+        statuses,
+        [
+            ("len", Status::Annotated),
+            ("Peek::peek", Status::Unverified),
+            ("encode", Status::Unverified),
+            ("helper", Status::Unverified),
+            ("first", Status::Annotated),
+            ("Buffer::len", Status::Annotated),
+            ("<Buffer as Clone>::clone", Status::Unverified),
+        ],
+    );
+    assert_eq!(Status::of(&functions[0]), Status::Missing);
+
+    let public: Vec<&Function> = functions.iter().filter(|f| f.public).collect();
+    let percentage = coverage::percentage(&public);
+    assert!((percentage - 200.0 / 7.0).abs() < 1e-9, "{}", percentage);
+    assert!((coverage::percentage(&[]) - 100.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_threshold() {
+    let metadata = serde_json::json!({
+        "no-panic": {
+            "min-coverage": 90,
+        },
+    });
+    assert_eq!(coverage::threshold(Some(&metadata)), Some(90.0));
+    let other = serde_json::json!({ "docs": { "rs": {} } });
+    assert_eq!(coverage::threshold(Some(&other)), None);
+    assert_eq!(coverage::threshold(None), None);
+}
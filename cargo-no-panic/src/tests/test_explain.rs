@@ -0,0 +1,141 @@
+use super::failure;
+use crate::cause::Cause;
+use crate::explain::{Chain, End, Step};
+use crate::ir::Frame;
+use crate::{explain, ir};
+use std::path::Path;
+
+// The IR of tests/ir/explain.ll, from a release build of:
+//
+//     #[inline(never)]
+//     fn helper(v: &[u32], i: usize) -> u32 {
+//         v[i] + 1
+//     }
+//
+//     fn middle(v: &[u32], i: usize) -> u32 {
+//         helper(v, i) * 2
+//     }
+//
+//     #[no_panic]
+//     fn demo(v: &[u32], i: usize) -> u32 {
+//         let a = middle(v, i);
+//         a.wrapping_add(v.first().copied().unwrap_or(0))
+//     }
+//
+//     fn main() {
+//         let n = std::env::args().count();
+//         std::process::exit(demo(&[1, 2, 3], n + 5) as i32);
+//     }
+const IR: &str = include_str!("../../tests/ir/explain.ll");
+
+#[test]
+fn test_ir() {
+    let functions = ir::parse(IR);
+
+    let helper = &functions["_ZN7explain6helper17h8098f1ddcc15b792E"];
+    assert_eq!(helper.display_name(), "explain::helper");
+    assert!(helper.defined);
+    let panic = helper
+        .calls
+        .iter()
+        .find(|call| call.name.as_deref() == Some("core::panicking::panic_bounds_check"))
+        .unwrap();
+    assert!(panic.noreturn);
+    assert_eq!(
+        panic.frames,
+        [Frame {
+            function: "explain::helper".to_owned(),
+            file: "src/main.rs".to_owned(),
+            line: 5,
+            column: 5,
+        }],
+    );
+
+    let panic_bounds_check = &functions[&panic.callee];
+    assert!(!panic_bounds_check.defined);
+    assert!(panic_bounds_check.noreturn);
+    assert!(!panic_bounds_check.nounwind);
+}
+
+#[test]
+fn test_chain() {
+    let functions = explain::load(&Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/ir")).unwrap();
+    let failure = failure("function `demo`", "explain", "src/main.rs:13:1");
+
+    let chain = explain::chain(&failure, &functions).unwrap();
+    let steps: Vec<(&str, &str, bool)> = chain
+        .steps
+        .iter()
+        .map(|step| (step.function.as_str(), step.location.as_str(), step.inlined))
+        .collect();
+    assert_eq!(
+        steps,
+        [
+            ("explain::demo", "src/main.rs:14:13", false),
+            ("explain::middle", "src/main.rs:9:5", true),
+            ("explain::helper", "src/main.rs:5:5", false),
+        ],
+    );
+    assert!(matches!(
+        &chain.end,
+        End::Panic(function) if function == "core::panicking::panic_bounds_check",
+    ));
+    assert_eq!(chain.others, 0);
+    assert_eq!(Cause::of(&chain), Some(Cause::BoundsCheck));
+}
+
+#[test]
+fn test_cause() {
+    let cause = |end: End, file: &str| {
+        let chain = Chain {
+            steps: vec![Step {
+                function: "demo".to_owned(),
+                location: format!("{}:2:5", file),
+                inlined: false,
+                callee: None,
+                reason: None,
+            }],
+            end,
+            others: 0,
+        };
+        Cause::of(&chain)
+    };
+    let panic = |function: &str| End::Panic(function.to_owned());
+
+    let entry_points = [
+        ("core::slice::index::slice_index_fail", Cause::BoundsCheck),
+        (
+            "core::panicking::panic_const::panic_const_mul_overflow",
+            Cause::Overflow,
+        ),
+        (
+            "core::panicking::panic_const::panic_const_rem_by_zero",
+            Cause::DivisionByZero,
+        ),
+        ("core::option::expect_failed", Cause::Unwrap),
+        ("core::panicking::assert_failed::<u32, u32>", Cause::Panic),
+        ("alloc::raw_vec::capacity_overflow", Cause::Allocation),
+        ("core::cell::panic_already_borrowed", Cause::RefCellBorrow),
+    ];
+    for (function, expected) in entry_points {
+        assert_eq!(
+            cause(panic(function), "src/main.rs"),
+            Some(expected),
+            "{}",
+            function
+        );
+    }
+
+    let opaque = End::Opaque("dep::twice".to_owned());
+    assert_eq!(cause(opaque, "src/main.rs"), Some(Cause::Opaque));
+    let std_panic = panic("core::panicking::panic_fmt");
+    assert_eq!(cause(std_panic, "library/core/src/slice/mod.rs"), None);
+    assert_eq!(cause(panic("std::process::abort"), "src/main.rs"), None);
+}
+
+#[test]
+fn test_unknown_failure() {
+    let functions = ir::parse(IR);
+    let failure = failure("function `other`", "explain", "src/main.rs:2:1");
+    assert!(explain::chain(&failure, &functions).is_none());
+}
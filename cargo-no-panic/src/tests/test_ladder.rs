@@ -0,0 +1,86 @@
+use super::{failure, function};
+use crate::ladder::{self, Outcome};
+use crate::parse::Failure;
+use crate::source::Function;
+use std::path::PathBuf;
+
+#[test]
+fn test_entries() {
+    let mut functions: Vec<Function> = [
+        ("first", "src/main.rs:4:1"),
+        ("nth", "src/main.rs:9:1"),
+        ("parse", "src/main.rs:14:1"),
+        ("unchecked", "src/main.rs:19:1"),
+        ("get", "src/main.rs:25:5"),
+        ("len", "util/src/lib.rs:2:1"),
+    ]
+    .iter()
+    .map(|&(name, location)| Function {
+        annotated: true,
+        standalone: true,
+        ..function(name, "demo", location)
+    })
+    .collect();
+    functions[3].annotated = false;
+    functions[4].standalone = false;
+    let outcome = |failures: Vec<Failure>, unchecked: &[&str]| Outcome {
+        failures,
+        unchecked: unchecked.iter().map(PathBuf::from).collect(),
+    };
+    let results = [
+        outcome(
+            vec![
+                failure("function `nth::<u8>`", "demo", "src/main.rs:9:1"),
+                failure("statement in function `parse`", "demo", "src/main.rs:16:5"),
+                failure("block", "demo", "src/main.rs:22:13"),
+                failure("function `len`", "demo", "util/src/lib.rs:2:1"),
+            ],
+            &[],
+        ),
+        outcome(
+            vec![
+                failure("statement in function `parse`", "demo", "src/main.rs:16:5"),
+                failure("block", "demo", "src/main.rs:22:13"),
+                failure("function `len`", "demo", "util/src/lib.rs:2:1"),
+            ],
+            &[],
+        ),
+        outcome(
+            vec![failure("block", "demo", "src/main.rs:22:13")],
+            &["util"],
+        ),
+        outcome(
+            vec![failure("block", "demo", "src/main.rs:22:13")],
+            &["util"],
+        ),
+    ];
+
+    let entries = ladder::entries(&functions, &results);
+    let entries: Vec<(&str, &str, bool, Option<usize>)> = entries
+        .iter()
+        .map(|entry| {
+            (
+                entry.subject.as_str(),
+                entry.location.as_str(),
+                entry.checked,
+                entry.proven_by(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        entries,
+        [
+            ("function `first`", "src/main.rs:4:1", true, Some(0)),
+            ("function `nth`", "src/main.rs:9:1", true, Some(1)),
+            ("function `parse`", "src/main.rs:14:1", true, Some(2)),
+            ("function `get`", "src/main.rs:25:5", false, None),
+            ("function `len`", "util/src/lib.rs:2:1", true, None),
+            ("block", "src/main.rs:22:13", true, None),
+        ],
+    );
+
+    // A package in the workspace root is unchecked as a whole.
+    let results = [outcome(Vec::new(), &[""])];
+    let entries = ladder::entries(&functions[..1], &results);
+    assert_eq!(entries[0].proven_by(), None);
+}
@@ -0,0 +1,207 @@
+use super::{failure, function};
+use crate::parse::{self, Failure, Reference};
+
+fn demo(references: Vec<Reference>) -> Failure {
+    Failure {
+        subject: "function `demo`".to_owned(),
+        fields: vec![
+            ("module".to_owned(), "loc".to_owned()),
+            ("location".to_owned(), "src/main.rs:4:1".to_owned()),
+            ("msg".to_owned(), "hot path".to_owned()),
+        ],
+        references,
+    }
+}
+
+fn reference(object: &str, function: &str) -> Reference {
+    Reference {
+        object: object.to_owned(),
+        function: Some(function.to_owned()),
+    }
+}
+
+const LLD_OBJECT: &str =
+    "/tmp/loc/target/release/deps/loc-b546130771929384.loc.bdde326b7922cc86-cgu.0.rcgu.o";
+
+#[test]
+fn test_gnu_ld() {
+    let output = include_str!("../../tests/linker/ld.txt");
+    let object =
+        "/tmp/loc/target/release/deps/loc-fba4849f89676d3e.loc.bdde326b7922cc86-cgu.0.rcgu.o";
+    let expected = demo(vec![reference(object, "loc::demo")]);
+    assert_eq!(parse::failures(output), [expected]);
+}
+
+#[test]
+fn test_gold() {
+    let output = include_str!("../../tests/linker/gold.txt");
+    let object =
+        "/tmp/loc/target/release/deps/loc-0f95e99912fa025a.loc.bdde326b7922cc86-cgu.0.rcgu.o";
+    let expected = demo(vec![reference(object, "loc::demo")]);
+    assert_eq!(parse::failures(output), [expected]);
+}
+
+#[test]
+fn test_lld() {
+    let output = include_str!("../../tests/linker/lld.txt");
+    let expected = demo(vec![reference(LLD_OBJECT, "loc::demo::h36552180165498e5")]);
+    assert_eq!(parse::failures(output), [expected]);
+}
+
+#[test]
+fn test_mold() {
+    let output = include_str!("../../tests/linker/mold.txt");
+    let demo = demo(vec![
+        reference(LLD_OBJECT, "loc::demo::h36552180165498e5"),
+        reference(LLD_OBJECT, "loc::main::h4480594170e520ca"),
+    ]);
+    let block = Failure {
+        references: vec![reference(
+            LLD_OBJECT,
+            "loc::checksum::sum::h0f1e2d3c4b5a6978",
+        )],
+        ..failure("block", "loc::checksum", "src/checksum.rs:12:9")
+    };
+    assert_eq!(parse::failures(output), [demo, block]);
+}
+
+// Each generic guard is referenced from its own drop impl, which names the
+// instantiation.
+fn assert_instantiations(output: &str) {
+    let failures = parse::failures(output);
+    let instantiations: Vec<(&str, Option<&str>, Vec<&str>)> = failures
+        .iter()
+        .map(|failure| {
+            let functions = failure
+                .references
+                .iter()
+                .map(|reference| {
+                    assert!(reference.object.contains("/deps/down-"));
+                    reference.function.as_deref().unwrap()
+                })
+                .collect();
+            (
+                failure.subject.as_str(),
+                failure.field("generics"),
+                functions,
+            )
+        })
+        .collect();
+    assert_eq!(
+        instantiations,
+        [
+            (
+                "function `later`",
+                Some("T, N"),
+                vec!["<up::later::{closure#0}::__NoPanic<u32, 3> as core::ops::drop::Drop>::drop"],
+            ),
+            (
+                "function `at`",
+                Some("T, N"),
+                vec!["<up::at::__NoPanic<i8, 3> as core::ops::drop::Drop>::drop"],
+            ),
+            (
+                "function `nth`",
+                Some("T"),
+                vec![
+                    "<up::nth::__NoPanic<u8> as core::ops::drop::Drop>::drop",
+                    "<up::nth::__NoPanic<u64> as core::ops::drop::Drop>::drop",
+                ],
+            ),
+            (
+                "function `Wrap::first`",
+                Some("Self"),
+                vec!["<<up::Wrap<_>>::first::__NoPanic<up::Wrap<u16>> as core::ops::drop::Drop>::drop"],
+            ),
+        ],
+    );
+}
+
+#[test]
+fn test_generic_gnu_ld() {
+    assert_instantiations(include_str!("../../tests/linker/generic-ld.txt"));
+}
+
+#[test]
+fn test_generic_gold() {
+    assert_instantiations(include_str!("../../tests/linker/generic-gold.txt"));
+}
+
+#[test]
+fn test_generic_lld() {
+    assert_instantiations(include_str!("../../tests/linker/generic-lld.txt"));
+}
+
+#[test]
+fn test_field() {
+    let failure = demo(Vec::new());
+    assert_eq!(failure.field("location"), Some("src/main.rs:4:1"));
+    assert_eq!(failure.field("owner"), None);
+}
+
+#[test]
+fn test_messages() {
+    let data = b"\x01\0index out of bounds\n\nERROR[no-panic]: detected panic in function `demo`\n  module: loc\n  location: src/main.rs:4:1\n  msg: hot path\n\xff\x00  len";
+    assert_eq!(parse::messages(data), [demo(Vec::new())]);
+}
+
+#[test]
+fn test_qualify() {
+    let functions = [
+        function("Reader::get", "demo", "src/lib.rs:5:5"),
+        function("twice", "demo", "src/lib.rs:9:1"),
+        function("outer", "demo", "src/lib.rs:12:1"),
+    ];
+    let qualified = |subject: &str, location: &str, function: &str| {
+        let mut failure = Failure {
+            references: vec![reference(LLD_OBJECT, function)],
+            ..failure(subject, "demo", location)
+        };
+        parse::qualify(&mut failure, &functions);
+        failure.subject
+    };
+
+    // From the source.
+    let subject = qualified("function `get`", "src/lib.rs:5:5", "demo::main");
+    assert_eq!(subject, "function `Reader::get`");
+    let subject = qualified("function `get::<u8>`", "src/lib.rs:5:5", "demo::main");
+    assert_eq!(subject, "function `Reader::get::<u8>`");
+    let subject = qualified("function `twice`", "src/lib.rs:9:1", "demo::Twice::twice");
+    assert_eq!(subject, "function `twice`");
+
+    // From the function referring to it.
+    let subject = qualified(
+        "statement in function `get`",
+        "src/lib.rs:6:9",
+        "demo::Reader::get::h0f1e2d3c4b5a6978",
+    );
+    assert_eq!(subject, "statement in function `Reader::get`");
+    let subject = qualified(
+        "function `get`",
+        "src/lib.rs:20:5",
+        "<demo::Reader<u8>>::get",
+    );
+    assert_eq!(subject, "function `Reader::get`");
+    let subject = qualified(
+        "function `peek`",
+        "src/lib.rs:20:5",
+        "<demo::Reader as demo::decode::Peek>::peek::{closure#0}",
+    );
+    assert_eq!(subject, "function `<Reader as Peek>::peek`");
+    let subject = qualified(
+        "function `first`",
+        "src/lib.rs:20:5",
+        "<<demo::Wrap<_>>::first::__NoPanic<demo::Wrap<u16>> as core::ops::drop::Drop>::drop",
+    );
+    assert_eq!(subject, "function `Wrap::first`");
+
+    // Not from a caller it was inlined into, or a function it is nested in.
+    let subject = qualified(
+        "function `get`",
+        "src/lib.rs:20:5",
+        "demo::main::h0f1e2d3c4b5a6978",
+    );
+    assert_eq!(subject, "function `get`");
+    let subject = qualified("function `get`", "src/lib.rs:20:5", "demo::outer::get");
+    assert_eq!(subject, "function `get`");
+}
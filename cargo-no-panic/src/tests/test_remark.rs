@@ -0,0 +1,71 @@
+use super::failure;
+use crate::remark::Reason;
+use crate::{explain, remark};
+use std::path::Path;
+
+#[test]
+fn test_parse() {
+    let reason = |rendered: &str| {
+        let remark = remark::parse(rendered).unwrap();
+        remark.reason
+    };
+
+    assert_eq!(
+        reason("note: /home/me/ex/src/main.rs:5:5 inline (missed): _RNvNtCsgEmfK2I1SDS_4core9panicking18panic_bounds_check will not be inlined into _ZN2ex6helper17h481ad35cbd03d2bbE because its definition is unavailable\n\n"),
+        Some(Reason::Unavailable),
+    );
+    assert_eq!(
+        reason("note: /home/me/ex/src/main.rs:14:62 inline (missed): '_ZN2ex3rec17haeb5cfb96a9a5411E' not inlined into '_ZN2ex3rec17haeb5cfb96a9a5411E' because it should never be inlined (cost=never): recursive\n\n"),
+        Some(Reason::Recursive),
+    );
+    assert_eq!(
+        reason("note: /rustc/59807616e1fa2540724bfbac14d7976d7e4a3860/library/core/src/iter/traits/iterator.rs:229:14 inline (missed): '_ZN4core4iter6traits8iterator8Iterator4fold17hc38a0e19dbee96c1E' not inlined into '_ZN2ex4main17hfd0ba94c7246e00dE' because too costly to inline (cost=320, threshold=0)\n\n"),
+        Some(Reason::TooCostly {
+            cost: "320".to_owned(),
+            threshold: "0".to_owned(),
+        }),
+    );
+    assert_eq!(
+        reason("note: /home/me/ex/src/main.rs:18:5 inline (success): '_ZN2ex6helper17h481ad35cbd03d2bbE' inlined into '_ZN2ex4demo17hf236a97f1ea6f1e5E' with (cost=-15, threshold=325) at callsite _ZN2ex4demo17hf236a97f1ea6f1e5E:1:5;\n\n"),
+        None,
+    );
+
+    // The undefined symbol of a no-panic error, quoted by a remark.
+    let rendered = "note: /home/me/ex/src/main.rs:16:1 inline (missed): \n\nERROR[no-panic]: detected panic in function `demo`\n  module: ex\n  location: src/main.rs:17:1\n will not be inlined into _ZN59_$LT$ex..demo..__NoPanic$u20$as$u20$core..ops..drop..Drop$GT$4drop17h1de11b737ac00f0fE because its definition is unavailable\n\n";
+    let remark = remark::parse(rendered).unwrap();
+    assert_eq!(remark.line, 16);
+    assert_eq!(remark.reason, Some(Reason::Unavailable));
+
+    assert!(remark::parse("note: required by a bound in `Send`").is_none());
+}
+
+#[test]
+fn test_attach() {
+    let functions = explain::load(&Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/ir")).unwrap();
+    let failure = failure("function `demo`", "explain", "src/main.rs:13:1");
+    let remarks = [
+        "note: /home/me/explain/src/main.rs:5:5 inline (missed): _RNvNtCsgEmfK2I1SDS_4core9panicking18panic_bounds_check will not be inlined into _ZN7explain6helper17h8098f1ddcc15b792E because its definition is unavailable\n\n",
+        "note: /home/me/explain/src/main.rs:9:5 inline (missed): '_ZN7explain6helper17h8098f1ddcc15b792E' not inlined into '_ZN7explain4demo17h13e83f3bf86c2519E' because it should never be inlined (cost=never): noinline function attribute\n\n",
+        "note: /home/me/explain/src/other.rs:9:5 inline (missed): '_ZN7explain6helper17h8098f1ddcc15b792E' not inlined into '_ZN7explain5other17h0b2c6d4a4bd2a1d4E' because too costly to inline (cost=80, threshold=45)\n\n",
+    ];
+    let remarks: Vec<remark::Remark> = remarks
+        .iter()
+        .map(|rendered| remark::parse(rendered).unwrap())
+        .collect();
+
+    let mut chain = explain::chain(&failure, &functions).unwrap();
+    remark::attach(&mut chain, &remarks);
+    let reasons: Vec<(&str, Option<&Reason>)> = chain
+        .steps
+        .iter()
+        .map(|step| (step.function.as_str(), step.reason.as_ref()))
+        .collect();
+    assert_eq!(
+        reasons,
+        [
+            ("explain::demo", None),
+            ("explain::middle", Some(&Reason::NoInline)),
+            ("explain::helper", None),
+        ],
+    );
+}
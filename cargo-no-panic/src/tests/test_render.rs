@@ -0,0 +1,44 @@
+use crate::parse::{self, Reference};
+use crate::render;
+
+fn reference_path(function: &str) -> String {
+    let reference = Reference {
+        object: String::new(),
+        function: Some(function.to_owned()),
+    };
+    reference.path().unwrap()
+}
+
+#[test]
+fn test_enclosing() {
+    let output = include_str!("../../tests/linker/mold.txt");
+    let failures = parse::failures(output);
+    let path = failures[1].references[0].path().unwrap();
+    assert_eq!(path, "loc::checksum::sum");
+    assert_eq!(
+        render::enclosing(&path, "loc::checksum").as_deref(),
+        Some("sum")
+    );
+    assert_eq!(
+        render::enclosing(&path, "loc").as_deref(),
+        Some("checksum::sum")
+    );
+    assert_eq!(render::enclosing(&path, "other"), None);
+
+    let guard = reference_path(
+        "<up::later::{closure#0}::__NoPanic<u32, 3> as core::ops::drop::Drop>::drop",
+    );
+    assert_eq!(render::enclosing(&guard, "up").as_deref(), Some("later"));
+    let guard = reference_path(
+        "<<up::Reader>::fill::{closure#0}::__NoPanic as core::ops::drop::Drop>::drop",
+    );
+    assert_eq!(
+        render::enclosing(&guard, "up").as_deref(),
+        Some("Reader::fill")
+    );
+    let guard = reference_path("_$LT$loc..checksum..sum..__NoPanic$u20$as$u20$core..ops..drop..Drop$GT$::drop::h36552180165498e5");
+    assert_eq!(
+        render::enclosing(&guard, "loc::checksum").as_deref(),
+        Some("sum")
+    );
+}
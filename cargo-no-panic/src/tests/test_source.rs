@@ -0,0 +1,112 @@
+use crate::source;
+use std::path::Path;
+
+#[test]
+fn test_functions() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/source");
+    let src_path = root.join("src/lib.rs");
+    let functions = source::functions("source", &src_path, &root).unwrap();
+    let functions: Vec<(String, &str, bool)> = functions
+        .iter()
+        .map(|function| {
+            let path = format!("{}::{}", function.module, function.name);
+            (path, function.location.as_str(), function.annotated)
+        })
+        .collect();
+    let expected = [
+        ("source::decode::util::len", "src/decode/util.rs:1:5", false),
+        (
+            "source::decode::util::checked::len",
+            "src/decode/util.rs:7:9",
+            true,
+        ),
+        ("source::decode::Peek::peek", "src/decode.rs:5:5", true),
+        ("source::encode::encode", "src/encode/mod.rs:2:5", true),
+        (
+            "source::helpers::inner::helper",
+            "src/misc/helpers.rs:3:5",
+            true,
+        ),
+        ("source::first", "src/lib.rs:9:5", true),
+        ("source::unchecked", "src/lib.rs:13:5", false),
+        ("source::Buffer::len", "src/lib.rs:21:9", true),
+        ("source::Buffer::at", "src/lib.rs:26:9", false),
+        ("source::<Buffer as Clone>::clone", "src/lib.rs:33:5", true),
+        ("source::generic", "src/lib.rs:38:5", false),
+        ("source::Reader::get", "src/lib.rs:45:9", false),
+        ("source::Reader::fill", "src/lib.rs:49:15", false),
+        ("source::tests::fixture", "src/lib.rs:54:5", false),
+        ("source::twice", "src/lib.rs:59:11", false),
+    ];
+    let expected: Vec<(String, &str, bool)> = expected
+        .iter()
+        .map(|&(path, location, annotated)| (path.to_owned(), location, annotated))
+        .collect();
+    assert_eq!(functions, expected);
+}
+
+#[test]
+fn test_candidates() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/source");
+    let src_path = root.join("src/lib.rs");
+    let functions = source::functions("source", &src_path, &root).unwrap();
+    let candidates: Vec<(&str, usize, usize, bool, bool)> = functions
+        .iter()
+        .filter_map(|function| {
+            let candidate = function.candidate.as_ref()?;
+            Some((
+                function.name.as_str(),
+                candidate.start.line,
+                candidate.start.column,
+                candidate.impl_start.is_some(),
+                candidate.eligible,
+            ))
+        })
+        .collect();
+    assert_eq!(
+        candidates,
+        [
+            ("len", 1, 0, false, true),
+            ("unchecked", 13, 0, false, true),
+            ("generic", 38, 0, false, false),
+            ("Reader::get", 45, 4, true, true),
+            ("Reader::fill", 49, 4, true, false),
+            ("fixture", 54, 4, false, false),
+            ("twice", 59, 10, false, true),
+        ],
+    );
+}
+
+#[test]
+fn test_public() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/source");
+    let src_path = root.join("src/lib.rs");
+    let functions = source::functions("source", &src_path, &root).unwrap();
+    let public: Vec<(&str, bool)> = functions
+        .iter()
+        .filter(|function| function.public)
+        .map(|function| (function.name.as_str(), function.standalone))
+        .collect();
+    assert_eq!(
+        public,
+        [
+            ("first", true),
+            ("unchecked", false),
+            ("Buffer::len", true),
+            ("Buffer::at", false),
+            ("generic", false),
+            ("Reader::get", false),
+            ("Reader::fill", false),
+        ],
+    );
+
+    let standalone: Vec<&str> = functions
+        .iter()
+        .filter(|function| function.annotated && !function.standalone)
+        .map(|function| function.name.as_str())
+        .collect();
+    assert_eq!(
+        standalone,
+        ["Peek::peek", "encode", "helper", "<Buffer as Clone>::clone"]
+    );
+}
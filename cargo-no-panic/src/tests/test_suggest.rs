@@ -0,0 +1,61 @@
+use crate::source::{self, Function};
+use crate::suggest;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn fixture() -> (Vec<Function>, PathBuf, String) {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/source");
+    let src_path = root.join("src/lib.rs");
+    let functions = source::functions("source", &src_path, &root).unwrap();
+    let content = fs::read_to_string(&src_path).unwrap();
+    (functions, src_path, content)
+}
+
+#[test]
+fn test_trial() {
+    let (functions, src_path, content) = fixture();
+    let edits = suggest::trial(&functions);
+    let expected = content
+        .replace(
+            "pub fn unchecked",
+            "#[::no_panic::no_panic(verify, id = \"no-panic-suggest-6\")] pub fn unchecked",
+        )
+        .replace("impl Reader {", "#[::no_panic::no_panic] impl Reader {")
+        .replace(
+            "    pub fn get",
+            "    #[::no_panic::no_panic(id = \"no-panic-suggest-11\")] pub fn get",
+        )
+        .replace(
+            "    pub async fn fill",
+            "    #[::no_panic::no_panic(skip)] pub async fn fill",
+        )
+        .replace(
+            "#[inline] fn twice",
+            "#[inline] #[::no_panic::no_panic(verify, id = \"no-panic-suggest-14\")] fn twice",
+        );
+    assert_eq!(suggest::apply(&content, &edits[&src_path]), expected);
+    assert_eq!(functions[14].name, "twice");
+    assert_eq!(suggest::index("no-panic-suggest-14"), Some(14));
+    assert_eq!(suggest::index("NET-1234"), None);
+}
+
+#[test]
+fn test_annotations() {
+    let (functions, src_path, content) = fixture();
+    let passed: Vec<bool> = functions
+        .iter()
+        .map(|function| matches!(function.name.as_str(), "Reader::get" | "twice"))
+        .collect();
+    let edits = suggest::annotations(&functions, &passed);
+    let expected = content
+        .replace("impl Reader {", "#[no_panic::no_panic]\nimpl Reader {")
+        .replace(
+            "    pub async fn fill",
+            "    #[no_panic::no_panic(skip)]\n    pub async fn fill",
+        )
+        .replace(
+            "#[inline] fn twice",
+            "#[inline] #[no_panic::no_panic(verify)] fn twice",
+        );
+    assert_eq!(suggest::apply(&content, &edits[&src_path]), expected);
+}
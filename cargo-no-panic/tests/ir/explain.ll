@@ -0,0 +1,680 @@
+; ModuleID = 'explain.847a9b70b5dc143-cgu.0'
+source_filename = "explain.847a9b70b5dc143-cgu.0"
+target datalayout = "e-m:e-p270:32:32-p271:32:32-p272:64:64-i64:64-i128:128-f80:128-n8:16:32:64-S128"
+target triple = "x86_64-unknown-linux-gnu"
+
+%"std::ffi::os_str::OsString" = type { %"std::sys::os_str::bytes::Buf" }
+%"std::sys::os_str::bytes::Buf" = type { %"alloc::vec::Vec<u8>" }
+%"alloc::vec::Vec<u8>" = type { %"alloc::raw_vec::RawVec<u8>", i64 }
+%"alloc::raw_vec::RawVec<u8>" = type { %"alloc::raw_vec::RawVecInner", %"core::marker::PhantomData<u8>" }
+%"alloc::raw_vec::RawVecInner" = type { i64, ptr, %"alloc::alloc::Global" }
+%"alloc::alloc::Global" = type {}
+%"core::marker::PhantomData<u8>" = type {}
+
+@vtable.0 = private unnamed_addr constant <{ [24 x i8], ptr, ptr, ptr }> <{ [24 x i8] c"\00\00\00\00\00\00\00\00\08\00\00\00\00\00\00\00\08\00\00\00\00\00\00\00", ptr @"_ZN4core3ops8function6FnOnce40call_once$u7b$$u7b$vtable.shim$u7d$$u7d$17h3817abf4a24d750dE", ptr @"_ZN3std2rt10lang_start28_$u7b$$u7b$closure$u7d$$u7d$17h0ae11af4798a1a5cE", ptr @"_ZN3std2rt10lang_start28_$u7b$$u7b$closure$u7d$$u7d$17h0ae11af4798a1a5cE" }>, align 8
+@alloc_03fa889ebefc3fd61240d008cccbcd8d = private unnamed_addr constant [12 x i8] c"\01\00\00\00\02\00\00\00\03\00\00\00", align 4
+@alloc_806c13c7682962bbd029107620e823a9 = private unnamed_addr constant [12 x i8] c"src/main.rs\00", align 1
+@alloc_f065755b64fc1f17e8239a2ae749c79d = private unnamed_addr constant <{ ptr, [16 x i8] }> <{ ptr @alloc_806c13c7682962bbd029107620e823a9, [16 x i8] c"\0B\00\00\00\00\00\00\00\05\00\00\00\05\00\00\00" }>, align 8
+@__rustc_debug_gdb_scripts_section__ = linkonce_odr unnamed_addr constant [34 x i8] c"\01gdb_load_rust_pretty_printers.py\00", section ".debug_gdb_scripts", align 1
+
+; std::rt::lang_start
+; Function Attrs: nonlazybind uwtable
+define hidden noundef i64 @_ZN3std2rt10lang_start17heabc1deb1feaa2baE(ptr noundef nonnull %main, i64 noundef %argc, ptr noundef %argv, i8 noundef %sigpipe) unnamed_addr #0 !dbg !8 {
+start:
+  %_7 = alloca [8 x i8], align 8
+  call void @llvm.lifetime.start.p0(ptr nonnull %_7), !dbg !14
+  store ptr %main, ptr %_7, align 8, !dbg !14
+; call std::rt::lang_start_internal
+  %_0 = call noundef i64 @_RNvNtCsjrHSEGnQ3l9_3std2rt19lang_start_internal(ptr noundef nonnull align 1 %_7, ptr noalias noundef readonly align 8 captures(address, read_provenance) dereferenceable(48) @vtable.0, i64 noundef %argc, ptr noundef %argv, i8 noundef %sigpipe), !dbg !15
+  call void @llvm.lifetime.end.p0(ptr nonnull %_7), !dbg !16
+  ret i64 %_0, !dbg !17
+}
+
+; std::rt::lang_start::{{closure}}
+; Function Attrs: inlinehint nonlazybind uwtable
+define internal noundef i32 @"_ZN3std2rt10lang_start28_$u7b$$u7b$closure$u7d$$u7d$17h0ae11af4798a1a5cE"(ptr noalias noundef readonly align 8 captures(none) dereferenceable(8) %_1) unnamed_addr #1 !dbg !18 {
+start:
+  %_4 = load ptr, ptr %_1, align 8, !dbg !20, !nonnull !13, !noundef !13
+; call std::sys::backtrace::__rust_begin_short_backtrace
+  tail call fastcc void @_ZN3std3sys9backtrace28__rust_begin_short_backtrace17h25304786b2923d11E(ptr noundef nonnull %_4) #11, !dbg !21
+  ret i32 0, !dbg !22
+}
+
+; std::sys::backtrace::__rust_begin_short_backtrace
+; Function Attrs: noinline nonlazybind uwtable
+define internal fastcc void @_ZN3std3sys9backtrace28__rust_begin_short_backtrace17h25304786b2923d11E(ptr noundef nonnull readonly captures(none) %f) unnamed_addr #2 !dbg !23 {
+start:
+  tail call void %f(), !dbg !27
+  tail call void asm sideeffect "", "~{memory}"() #12, !dbg !35, !srcloc !41
+  ret void, !dbg !42
+}
+
+; core::ops::function::FnOnce::call_once{{vtable.shim}}
+; Function Attrs: inlinehint nonlazybind uwtable
+define internal noundef i32 @"_ZN4core3ops8function6FnOnce40call_once$u7b$$u7b$vtable.shim$u7d$$u7d$17h3817abf4a24d750dE"(ptr noundef readonly captures(none) %_1) unnamed_addr #1 personality ptr @rust_eh_personality !dbg !43 {
+start:
+  %0 = load ptr, ptr %_1, align 8, !dbg !44, !nonnull !13, !noundef !13
+; call std::sys::backtrace::__rust_begin_short_backtrace
+  tail call fastcc void @_ZN3std3sys9backtrace28__rust_begin_short_backtrace17h25304786b2923d11E(ptr noundef nonnull readonly %0) #11, !dbg !45, !noalias !49
+  ret i32 0, !dbg !44
+}
+
+; core::ptr::drop_in_place<std::env::Args>
+; Function Attrs: nounwind nonlazybind uwtable
+define internal fastcc void @"_ZN4core3ptr35drop_in_place$LT$std..env..Args$GT$17heb1638584899d53eE"(ptr noalias noundef nonnull readonly align 8 captures(none) dereferenceable(32) %_1) unnamed_addr #3 personality ptr @rust_eh_personality !dbg !52 {
+start:
+  tail call void @llvm.experimental.noalias.scope.decl(metadata !55), !dbg !58
+  tail call void @llvm.experimental.noalias.scope.decl(metadata !59), !dbg !62
+  tail call void @llvm.experimental.noalias.scope.decl(metadata !65), !dbg !68
+  tail call void @llvm.experimental.noalias.scope.decl(metadata !71), !dbg !74
+  %0 = getelementptr inbounds nuw i8, ptr %_1, i64 8, !dbg !77
+  %self.val.i.i.i.i = load ptr, ptr %0, align 8, !dbg !77, !alias.scope !86, !nonnull !13, !noundef !13
+  %1 = getelementptr inbounds nuw i8, ptr %_1, i64 24, !dbg !77
+  %self.val1.i.i.i.i = load ptr, ptr %1, align 8, !dbg !77, !alias.scope !86, !nonnull !13, !noundef !13
+  %2 = ptrtoint ptr %self.val1.i.i.i.i to i64, !dbg !87
+  %3 = ptrtoint ptr %self.val.i.i.i.i to i64, !dbg !87
+  %4 = sub nuw i64 %2, %3, !dbg !87
+  %5 = udiv exact i64 %4, 24, !dbg !87
+  tail call void @llvm.experimental.noalias.scope.decl(metadata !120), !dbg !123
+  %_710.i.i.i.i.i = icmp eq ptr %self.val1.i.i.i.i, %self.val.i.i.i.i, !dbg !124
+  br i1 %_710.i.i.i.i.i, label %bb2.i.i.i.i, label %bb5.i.i.i.i.i, !dbg !124
+
+bb5.i.i.i.i.i:                                    ; preds = %start, %"_ZN4core3ptr47drop_in_place$LT$std..ffi..os_str..OsString$GT$17h313d29781e168e72E.exit.i.i.i.i.i"
+  %_3.sroa.0.011.i.i.i.i.i = phi i64 [ %6, %"_ZN4core3ptr47drop_in_place$LT$std..ffi..os_str..OsString$GT$17h313d29781e168e72E.exit.i.i.i.i.i" ], [ 0, %start ]
+  %_6.i.i.i.i.i = getelementptr inbounds nuw %"std::ffi::os_str::OsString", ptr %self.val.i.i.i.i, i64 %_3.sroa.0.011.i.i.i.i.i, !dbg !124
+  %6 = add nuw i64 %_3.sroa.0.011.i.i.i.i.i, 1, !dbg !124
+  %_6.val.i.i.i.i.i = load i64, ptr %_6.i.i.i.i.i, align 8, !dbg !124, !alias.scope !120, !noalias !86
+  %7 = icmp eq i64 %_6.val.i.i.i.i.i, 0, !dbg !127
+  br i1 %7, label %"_ZN4core3ptr47drop_in_place$LT$std..ffi..os_str..OsString$GT$17h313d29781e168e72E.exit.i.i.i.i.i", label %bb2.i.i.i4.i.i.i.i.i.i.i.i, !dbg !127
+
+bb2.i.i.i4.i.i.i.i.i.i.i.i:                       ; preds = %bb5.i.i.i.i.i
+  %8 = getelementptr i8, ptr %_6.i.i.i.i.i, i64 8, !dbg !124
+  %_6.val7.i.i.i.i.i = load ptr, ptr %8, align 8, !dbg !124, !alias.scope !120, !noalias !86, !nonnull !13, !noundef !13
+; call __rustc::__rust_dealloc
+  tail call void @_RNvCsfLfy6EI15iL_7___rustc14___rust_dealloc(ptr noundef nonnull %_6.val7.i.i.i.i.i, i64 noundef %_6.val.i.i.i.i.i, i64 noundef range(i64 1, -9223372036854775807) 1) #12, !dbg !147, !noalias !160
+  br label %"_ZN4core3ptr47drop_in_place$LT$std..ffi..os_str..OsString$GT$17h313d29781e168e72E.exit.i.i.i.i.i", !dbg !161
+
+"_ZN4core3ptr47drop_in_place$LT$std..ffi..os_str..OsString$GT$17h313d29781e168e72E.exit.i.i.i.i.i": ; preds = %bb2.i.i.i4.i.i.i.i.i.i.i.i, %bb5.i.i.i.i.i
+  %_7.i.i.i.i.i = icmp eq i64 %6, %5, !dbg !124
+  br i1 %_7.i.i.i.i.i, label %bb2.i.i.i.i, label %bb5.i.i.i.i.i, !dbg !124
+
+bb2.i.i.i.i:                                      ; preds = %"_ZN4core3ptr47drop_in_place$LT$std..ffi..os_str..OsString$GT$17h313d29781e168e72E.exit.i.i.i.i.i", %start
+  %9 = getelementptr inbounds nuw i8, ptr %_1, i64 16, !dbg !162
+  %capacity1.i.i3.i.i.i.i = load i64, ptr %9, align 8, !dbg !162, !alias.scope !86, !noundef !13
+  %10 = icmp eq i64 %capacity1.i.i3.i.i.i.i, 0, !dbg !170
+  br i1 %10, label %"_ZN4core3ptr37drop_in_place$LT$std..env..ArgsOs$GT$17ha61acf9dba83f8caE.exit", label %bb2.i.i.i.i.i4.i.i.i.i, !dbg !170
+
+bb2.i.i.i.i.i4.i.i.i.i:                           ; preds = %bb2.i.i.i.i
+  %ptr.i.i5.i.i.i.i = load ptr, ptr %_1, align 8, !dbg !177, !alias.scope !86, !nonnull !13, !noundef !13
+  %alloc_size.i.i.i.i.i.i6.i.i.i.i = mul nuw i64 %capacity1.i.i3.i.i.i.i, 24, !dbg !178
+; call __rustc::__rust_dealloc
+  tail call void @_RNvCsfLfy6EI15iL_7___rustc14___rust_dealloc(ptr noundef nonnull %ptr.i.i5.i.i.i.i, i64 noundef %alloc_size.i.i.i.i.i.i6.i.i.i.i, i64 noundef range(i64 1, -9223372036854775807) 8) #12, !dbg !184, !noalias !86
+  br label %"_ZN4core3ptr37drop_in_place$LT$std..env..ArgsOs$GT$17ha61acf9dba83f8caE.exit", !dbg !189
+
+"_ZN4core3ptr37drop_in_place$LT$std..env..ArgsOs$GT$17ha61acf9dba83f8caE.exit": ; preds = %bb2.i.i.i.i, %bb2.i.i.i.i.i4.i.i.i.i
+  ret void, !dbg !58
+}
+
+; core::iter::traits::iterator::Iterator::fold
+; Function Attrs: inlinehint nonlazybind uwtable
+define internal fastcc noundef i64 @_ZN4core4iter6traits8iterator8Iterator4fold17hb1eeeb7fe1755506E(ptr dead_on_return noalias noundef nonnull align 8 captures(address) dereferenceable(32) %self) unnamed_addr #1 personality ptr @rust_eh_personality !dbg !190 {
+start:
+  %_5 = alloca [24 x i8], align 8
+  %x.sroa.2.0._5.sroa_idx = getelementptr inbounds nuw i8, ptr %_5, i64 8
+  br label %bb1, !dbg !195
+
+bb1:                                              ; preds = %bb4, %start
+  %accum.sroa.0.0 = phi i64 [ 0, %start ], [ %_4.0.i, %bb4 ], !dbg !197
+  call void @llvm.lifetime.start.p0(ptr nonnull %_5), !dbg !198
+; invoke <std::env::Args as core::iter::traits::iterator::Iterator>::next
+  invoke void @_RNvXsa_NtCsjrHSEGnQ3l9_3std3envNtB5_4ArgsNtNtNtNtCsgEmfK2I1SDS_4core4iter6traits8iterator8Iterator4next(ptr noalias noundef nonnull sret([24 x i8]) align 8 captures(none) dereferenceable(24) %_5, ptr noalias noundef nonnull align 8 dereferenceable(32) %self)
+          to label %bb2 unwind label %cleanup, !dbg !200
+
+cleanup:                                          ; preds = %bb1
+  %0 = landingpad { ptr, i32 }
+          cleanup
+; call core::ptr::drop_in_place<std::env::Args>
+  tail call fastcc void @"_ZN4core3ptr35drop_in_place$LT$std..env..Args$GT$17heb1638584899d53eE"(ptr noalias noundef align 8 dereferenceable(32) %self) #13, !dbg !201
+  resume { ptr, i32 } %0, !dbg !202
+
+bb2:                                              ; preds = %bb1
+  %1 = load i64, ptr %_5, align 8, !dbg !198, !range !203, !noundef !13
+  switch i64 %1, label %bb2.i.i.i4.i.i.i [
+    i64 -9223372036854775808, label %bb5
+    i64 0, label %bb4
+  ], !dbg !204
+
+bb2.i.i.i4.i.i.i:                                 ; preds = %bb2
+  %x.sroa.2.0.copyload = load ptr, ptr %x.sroa.2.0._5.sroa_idx, align 8, !dbg !205, !nonnull !13, !noundef !13
+; call __rustc::__rust_dealloc
+  tail call void @_RNvCsfLfy6EI15iL_7___rustc14___rust_dealloc(ptr noundef nonnull %x.sroa.2.0.copyload, i64 noundef %1, i64 noundef range(i64 1, -9223372036854775807) 1) #12, !dbg !206
+  br label %bb4, !dbg !220
+
+bb5:                                              ; preds = %bb2
+  call void @llvm.lifetime.end.p0(ptr nonnull %_5), !dbg !221
+  tail call void @llvm.experimental.noalias.scope.decl(metadata !222), !dbg !201
+  tail call void @llvm.experimental.noalias.scope.decl(metadata !225), !dbg !228
+  tail call void @llvm.experimental.noalias.scope.decl(metadata !230), !dbg !233
+  tail call void @llvm.experimental.noalias.scope.decl(metadata !235), !dbg !238
+  tail call void @llvm.experimental.noalias.scope.decl(metadata !240), !dbg !243
+  %2 = getelementptr inbounds nuw i8, ptr %self, i64 8, !dbg !245
+  %self.val.i.i.i.i.i = load ptr, ptr %2, align 8, !dbg !245, !alias.scope !247, !nonnull !13, !noundef !13
+  %3 = getelementptr inbounds nuw i8, ptr %self, i64 24, !dbg !245
+  %self.val1.i.i.i.i.i = load ptr, ptr %3, align 8, !dbg !245, !alias.scope !247, !nonnull !13, !noundef !13
+  %4 = ptrtoint ptr %self.val1.i.i.i.i.i to i64, !dbg !248
+  %5 = ptrtoint ptr %self.val.i.i.i.i.i to i64, !dbg !248
+  %6 = sub nuw i64 %4, %5, !dbg !248
+  %7 = udiv exact i64 %6, 24, !dbg !248
+  tail call void @llvm.experimental.noalias.scope.decl(metadata !256), !dbg !259
+  %_710.i.i.i.i.i.i = icmp eq ptr %self.val1.i.i.i.i.i, %self.val.i.i.i.i.i, !dbg !260
+  br i1 %_710.i.i.i.i.i.i, label %bb2.i.i.i.i.i, label %bb5.i.i.i.i.i.i, !dbg !260
+
+bb5.i.i.i.i.i.i:                                  ; preds = %bb5, %"_ZN4core3ptr47drop_in_place$LT$std..ffi..os_str..OsString$GT$17h313d29781e168e72E.exit.i.i.i.i.i.i"
+  %_3.sroa.0.011.i.i.i.i.i.i = phi i64 [ %8, %"_ZN4core3ptr47drop_in_place$LT$std..ffi..os_str..OsString$GT$17h313d29781e168e72E.exit.i.i.i.i.i.i" ], [ 0, %bb5 ]
+  %_6.i.i.i.i.i.i = getelementptr inbounds nuw %"std::ffi::os_str::OsString", ptr %self.val.i.i.i.i.i, i64 %_3.sroa.0.011.i.i.i.i.i.i, !dbg !260
+  %8 = add nuw i64 %_3.sroa.0.011.i.i.i.i.i.i, 1, !dbg !260
+  %_6.val.i.i.i.i.i.i = load i64, ptr %_6.i.i.i.i.i.i, align 8, !dbg !260, !alias.scope !256, !noalias !247
+  %9 = icmp eq i64 %_6.val.i.i.i.i.i.i, 0, !dbg !262
+  br i1 %9, label %"_ZN4core3ptr47drop_in_place$LT$std..ffi..os_str..OsString$GT$17h313d29781e168e72E.exit.i.i.i.i.i.i", label %bb2.i.i.i4.i.i.i.i.i.i.i.i.i, !dbg !262
+
+bb2.i.i.i4.i.i.i.i.i.i.i.i.i:                     ; preds = %bb5.i.i.i.i.i.i
+  %10 = getelementptr i8, ptr %_6.i.i.i.i.i.i, i64 8, !dbg !260
+  %_6.val7.i.i.i.i.i.i = load ptr, ptr %10, align 8, !dbg !260, !alias.scope !256, !noalias !247, !nonnull !13, !noundef !13
+; call __rustc::__rust_dealloc
+  tail call void @_RNvCsfLfy6EI15iL_7___rustc14___rust_dealloc(ptr noundef nonnull %_6.val7.i.i.i.i.i.i, i64 noundef %_6.val.i.i.i.i.i.i, i64 noundef range(i64 1, -9223372036854775807) 1) #12, !dbg !270, !noalias !275
+  br label %"_ZN4core3ptr47drop_in_place$LT$std..ffi..os_str..OsString$GT$17h313d29781e168e72E.exit.i.i.i.i.i.i", !dbg !276
+
+"_ZN4core3ptr47drop_in_place$LT$std..ffi..os_str..OsString$GT$17h313d29781e168e72E.exit.i.i.i.i.i.i": ; preds = %bb2.i.i.i4.i.i.i.i.i.i.i.i.i, %bb5.i.i.i.i.i.i
+  %_7.i.i.i.i.i.i = icmp eq i64 %8, %7, !dbg !260
+  br i1 %_7.i.i.i.i.i.i, label %bb2.i.i.i.i.i, label %bb5.i.i.i.i.i.i, !dbg !260
+
+bb2.i.i.i.i.i:                                    ; preds = %"_ZN4core3ptr47drop_in_place$LT$std..ffi..os_str..OsString$GT$17h313d29781e168e72E.exit.i.i.i.i.i.i", %bb5
+  %11 = getelementptr inbounds nuw i8, ptr %self, i64 16, !dbg !277
+  %capacity1.i.i3.i.i.i.i.i = load i64, ptr %11, align 8, !dbg !277, !alias.scope !247, !noundef !13
+  %12 = icmp eq i64 %capacity1.i.i3.i.i.i.i.i, 0, !dbg !280
+  br i1 %12, label %"_ZN4core3ptr35drop_in_place$LT$std..env..Args$GT$17heb1638584899d53eE.exit", label %bb2.i.i.i.i.i4.i.i.i.i.i, !dbg !280
+
+bb2.i.i.i.i.i4.i.i.i.i.i:                         ; preds = %bb2.i.i.i.i.i
+  %ptr.i.i5.i.i.i.i.i = load ptr, ptr %self, align 8, !dbg !285, !alias.scope !247, !nonnull !13, !noundef !13
+  %alloc_size.i.i.i.i.i.i6.i.i.i.i.i = mul nuw i64 %capacity1.i.i3.i.i.i.i.i, 24, !dbg !286
+; call __rustc::__rust_dealloc
+  tail call void @_RNvCsfLfy6EI15iL_7___rustc14___rust_dealloc(ptr noundef nonnull %ptr.i.i5.i.i.i.i.i, i64 noundef %alloc_size.i.i.i.i.i.i6.i.i.i.i.i, i64 noundef range(i64 1, -9223372036854775807) 8) #12, !dbg !288, !noalias !247
+  br label %"_ZN4core3ptr35drop_in_place$LT$std..env..Args$GT$17heb1638584899d53eE.exit", !dbg !293
+
+"_ZN4core3ptr35drop_in_place$LT$std..env..Args$GT$17heb1638584899d53eE.exit": ; preds = %bb2.i.i.i.i.i, %bb2.i.i.i.i.i4.i.i.i.i.i
+  ret i64 %accum.sroa.0.0, !dbg !294
+
+bb4:                                              ; preds = %bb2, %bb2.i.i.i4.i.i.i
+  %_4.0.i = add i64 %accum.sroa.0.0, 1, !dbg !295
+  call void @llvm.lifetime.end.p0(ptr nonnull %_5), !dbg !221
+  br label %bb1, !dbg !195
+}
+
+; explain::demo
+; Function Attrs: inlinehint nonlazybind uwtable
+define internal fastcc noundef range(i32 1, 0) i32 @_ZN7explain4demo17h13e83f3bf86c2519E(i64 noundef %i) unnamed_addr #1 personality ptr @rust_eh_personality !dbg !296 {
+start:
+; invoke explain::helper
+  %_3.i1 = invoke fastcc noundef i32 @_ZN7explain6helper17h8098f1ddcc15b792E(i64 noundef %i) #11
+          to label %bb6 unwind label %cleanup, !dbg !299
+
+cleanup:                                          ; preds = %start
+  %0 = landingpad { ptr, i32 }
+          cleanup
+  tail call void @"\0A\0AERROR[no-panic]: detected panic in function `demo`\0A  module: explain\0A  location: src/main.rs:13:1\0A"() #14, !dbg !308
+  unreachable, !dbg !308
+
+bb6:                                              ; preds = %start
+  %_0.i = shl i32 %_3.i1, 1, !dbg !299
+  %__result = or disjoint i32 %_0.i, 1, !dbg !314
+  ret i32 %__result, !dbg !319
+}
+
+; explain::main
+; Function Attrs: noreturn nonlazybind uwtable
+define hidden void @_ZN7explain4main17h14301b2c4ccd26f8E() unnamed_addr #4 !dbg !320 {
+start:
+  %_2 = alloca [32 x i8], align 8
+  call void @llvm.lifetime.start.p0(ptr nonnull %_2), !dbg !321
+; call std::env::args
+  call void @_RNvNtCsjrHSEGnQ3l9_3std3env4args(ptr noalias noundef nonnull sret([32 x i8]) align 8 captures(none) dereferenceable(32) %_2), !dbg !321
+; call core::iter::traits::iterator::Iterator::fold
+  %n = call fastcc noundef i64 @_ZN4core4iter6traits8iterator8Iterator4fold17hb1eeeb7fe1755506E(ptr noalias noundef align 8 captures(address) dereferenceable(32) %_2) #15, !dbg !322
+  call void @llvm.lifetime.end.p0(ptr nonnull %_2), !dbg !325
+  %_7 = add i64 %n, 5, !dbg !326
+; call explain::demo
+  %_5 = call fastcc noundef i32 @_ZN7explain4demo17h13e83f3bf86c2519E(i64 noundef %_7) #15, !dbg !328
+; call std::process::exit
+  call void @_RNvNtCsjrHSEGnQ3l9_3std7process4exit(i32 noundef %_5) #16, !dbg !329
+  unreachable, !dbg !329
+}
+
+; explain::helper
+; Function Attrs: noinline nonlazybind uwtable
+define internal fastcc noundef i32 @_ZN7explain6helper17h8098f1ddcc15b792E(i64 noundef %i) unnamed_addr #2 !dbg !330 {
+start:
+  %_5 = icmp ult i64 %i, 3, !dbg !331
+  br i1 %_5, label %bb1, label %panic, !dbg !331
+
+bb1:                                              ; preds = %start
+  %0 = getelementptr inbounds nuw i32, ptr @alloc_03fa889ebefc3fd61240d008cccbcd8d, i64 %i, !dbg !331
+  %_3 = load i32, ptr %0, align 4, !dbg !331, !noundef !13
+  %_0 = add i32 %_3, 1, !dbg !331
+  ret i32 %_0, !dbg !332
+
+panic:                                            ; preds = %start
+; call core::panicking::panic_bounds_check
+  tail call void @_RNvNtCsgEmfK2I1SDS_4core9panicking18panic_bounds_check(i64 noundef %i, i64 noundef 3, ptr noalias noundef readonly align 8 captures(address, read_provenance) dereferenceable(24) @alloc_f065755b64fc1f17e8239a2ae749c79d) #17, !dbg !331
+  unreachable, !dbg !331
+}
+
+; __rustc::__rust_dealloc
+; Function Attrs: nounwind nonlazybind allockind("free") uwtable
+declare void @_RNvCsfLfy6EI15iL_7___rustc14___rust_dealloc(ptr allocptr noundef captures(address), i64 noundef, i64 noundef range(i64 1, -9223372036854775807)) unnamed_addr #5
+
+; Function Attrs: mustprogress nocallback nofree nosync nounwind willreturn memory(argmem: readwrite)
+declare void @llvm.lifetime.start.p0(ptr captures(none)) #6
+
+; Function Attrs: mustprogress nocallback nofree nosync nounwind willreturn memory(argmem: readwrite)
+declare void @llvm.lifetime.end.p0(ptr captures(none)) #6
+
+; std::rt::lang_start_internal
+; Function Attrs: nonlazybind uwtable
+declare noundef i64 @_RNvNtCsjrHSEGnQ3l9_3std2rt19lang_start_internal(ptr noundef nonnull align 1, ptr noalias noundef readonly align 8 captures(address, read_provenance) dereferenceable(48), i64 noundef, ptr noundef, i8 noundef) unnamed_addr #0
+
+; Function Attrs: nounwind nonlazybind uwtable
+declare noundef range(i32 0, 10) i32 @rust_eh_personality(i32 noundef, i32 noundef, i64 noundef, ptr noundef, ptr noundef) unnamed_addr #3
+
+; <std::env::Args as core::iter::traits::iterator::Iterator>::next
+; Function Attrs: nonlazybind uwtable
+declare void @_RNvXsa_NtCsjrHSEGnQ3l9_3std3envNtB5_4ArgsNtNtNtNtCsgEmfK2I1SDS_4core4iter6traits8iterator8Iterator4next(ptr dead_on_unwind noalias noundef writable sret([24 x i8]) align 8 captures(none) dereferenceable(24), ptr noalias noundef align 8 dereferenceable(32)) unnamed_addr #0
+
+; Function Attrs: noreturn nounwind nonlazybind uwtable
+declare void @"\0A\0AERROR[no-panic]: detected panic in function `demo`\0A  module: explain\0A  location: src/main.rs:13:1\0A"() unnamed_addr #7
+
+; std::env::args
+; Function Attrs: nonlazybind uwtable
+declare void @_RNvNtCsjrHSEGnQ3l9_3std3env4args(ptr dead_on_unwind noalias noundef writable sret([32 x i8]) align 8 captures(none) dereferenceable(32)) unnamed_addr #0
+
+; std::process::exit
+; Function Attrs: noreturn nonlazybind uwtable
+declare void @_RNvNtCsjrHSEGnQ3l9_3std7process4exit(i32 noundef) unnamed_addr #4
+
+; core::panicking::panic_bounds_check
+; Function Attrs: cold minsize noinline noreturn nonlazybind optsize uwtable
+declare void @_RNvNtCsgEmfK2I1SDS_4core9panicking18panic_bounds_check(i64 noundef, i64 noundef, ptr noalias noundef readonly align 8 captures(address, read_provenance) dereferenceable(24)) unnamed_addr #8
+
+; Function Attrs: nonlazybind
+define noundef i32 @main(i32 %0, ptr %1) unnamed_addr #9 {
+top:
+  %_7.i = alloca [8 x i8], align 8
+  %2 = load volatile i8, ptr @__rustc_debug_gdb_scripts_section__, align 1
+  %3 = sext i32 %0 to i64
+  call void @llvm.lifetime.start.p0(ptr nonnull %_7.i), !dbg !14
+  store ptr @_ZN7explain4main17h14301b2c4ccd26f8E, ptr %_7.i, align 8, !dbg !14
+; call std::rt::lang_start_internal
+  %_0.i = call noundef i64 @_RNvNtCsjrHSEGnQ3l9_3std2rt19lang_start_internal(ptr noundef nonnull align 1 %_7.i, ptr noalias noundef readonly align 8 captures(address, read_provenance) dereferenceable(48) @vtable.0, i64 noundef %3, ptr noundef %1, i8 noundef 0), !dbg !15
+  call void @llvm.lifetime.end.p0(ptr nonnull %_7.i), !dbg !16
+  %4 = trunc i64 %_0.i to i32
+  ret i32 %4
+}
+
+; Function Attrs: nocallback nofree nosync nounwind willreturn memory(inaccessiblemem: readwrite)
+declare void @llvm.experimental.noalias.scope.decl(metadata) #10
+
+attributes #0 = { nonlazybind uwtable "probe-stack"="inline-asm" "target-cpu"="x86-64" }
+attributes #1 = { inlinehint nonlazybind uwtable "probe-stack"="inline-asm" "target-cpu"="x86-64" }
+attributes #2 = { noinline nonlazybind uwtable "probe-stack"="inline-asm" "target-cpu"="x86-64" }
+attributes #3 = { nounwind nonlazybind uwtable "probe-stack"="inline-asm" "target-cpu"="x86-64" }
+attributes #4 = { noreturn nonlazybind uwtable "probe-stack"="inline-asm" "target-cpu"="x86-64" }
+attributes #5 = { nounwind nonlazybind allockind("free") uwtable "alloc-family"="__rust_alloc" "probe-stack"="inline-asm" "target-cpu"="x86-64" }
+attributes #6 = { mustprogress nocallback nofree nosync nounwind willreturn memory(argmem: readwrite) }
+attributes #7 = { noreturn nounwind nonlazybind uwtable "probe-stack"="inline-asm" "target-cpu"="x86-64" }
+attributes #8 = { cold minsize noinline noreturn nonlazybind optsize uwtable "probe-stack"="inline-asm" "target-cpu"="x86-64" }
+attributes #9 = { nonlazybind "probe-stack"="inline-asm" "target-cpu"="x86-64" }
+attributes #10 = { nocallback nofree nosync nounwind willreturn memory(inaccessiblemem: readwrite) }
+attributes #11 = { noinline }
+attributes #12 = { nounwind }
+attributes #13 = { cold }
+attributes #14 = { noreturn nounwind }
+attributes #15 = { inlinehint }
+attributes #16 = { noreturn }
+attributes #17 = { noinline noreturn }
+
+!llvm.module.flags = !{!0, !1, !2, !3, !4}
+!llvm.ident = !{!5}
+!llvm.dbg.cu = !{!6}
+
+!0 = !{i32 8, !"PIC Level", i32 2}
+!1 = !{i32 7, !"PIE Level", i32 2}
+!2 = !{i32 2, !"RtLibUseGOT", i32 1}
+!3 = !{i32 7, !"Dwarf Version", i32 4}
+!4 = !{i32 2, !"Debug Info Version", i32 3}
+!5 = !{!"rustc version 1.95.0 (59807616e 2026-04-14)"}
+!6 = distinct !DICompileUnit(language: DW_LANG_Rust, file: !7, producer: "clang LLVM (rustc version 1.95.0 (59807616e 2026-04-14))", isOptimized: true, runtimeVersion: 0, emissionKind: LineTablesOnly, splitDebugInlining: false, nameTableKind: None)
+!7 = !DIFile(filename: "src/main.rs/@/explain.847a9b70b5dc143-cgu.0", directory: "/explain")
+!8 = distinct !DISubprogram(name: "lang_start<()>", linkageName: "_ZN3std2rt10lang_start17heabc1deb1feaa2baE", scope: !10, file: !9, line: 199, type: !12, scopeLine: 199, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!9 = !DIFile(filename: "library/std/src/rt.rs", directory: "/rustc/59807616e1fa2540724bfbac14d7976d7e4a3860", checksumkind: CSK_MD5, checksum: "83eadca7bec2ebce94abb46f51902baa")
+!10 = !DINamespace(name: "rt", scope: !11)
+!11 = !DINamespace(name: "std", scope: null)
+!12 = !DISubroutineType(types: !13)
+!13 = !{}
+!14 = !DILocation(line: 206, column: 10, scope: !8)
+!15 = !DILocation(line: 205, column: 5, scope: !8)
+!16 = !DILocation(line: 210, column: 5, scope: !8)
+!17 = !DILocation(line: 211, column: 2, scope: !8)
+!18 = distinct !DISubprogram(name: "{closure#0}<()>", linkageName: "_ZN3std2rt10lang_start28_$u7b$$u7b$closure$u7d$$u7d$17h0ae11af4798a1a5cE", scope: !19, file: !9, line: 206, type: !12, scopeLine: 206, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!19 = !DINamespace(name: "lang_start", scope: !10)
+!20 = !DILocation(line: 206, column: 70, scope: !18)
+!21 = !DILocation(line: 206, column: 18, scope: !18)
+!22 = !DILocation(line: 206, column: 93, scope: !18)
+!23 = distinct !DISubprogram(name: "__rust_begin_short_backtrace<fn(), ()>", linkageName: "_ZN3std3sys9backtrace28__rust_begin_short_backtrace17h25304786b2923d11E", scope: !25, file: !24, line: 162, type: !12, scopeLine: 162, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!24 = !DIFile(filename: "library/std/src/sys/backtrace.rs", directory: "/rustc/59807616e1fa2540724bfbac14d7976d7e4a3860", checksumkind: CSK_MD5, checksum: "0469076862be40bd9e65965440a24fae")
+!25 = !DINamespace(name: "backtrace", scope: !26)
+!26 = !DINamespace(name: "sys", scope: !11)
+!27 = !DILocation(line: 250, column: 5, scope: !28, inlinedAt: !34)
+!28 = distinct !DISubprogram(name: "call_once<fn(), ()>", linkageName: "_ZN4core3ops8function6FnOnce9call_once17h474f806d61e15256E", scope: !30, file: !29, line: 250, type: !12, scopeLine: 250, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!29 = !DIFile(filename: "library/core/src/ops/function.rs", directory: "/rustc/59807616e1fa2540724bfbac14d7976d7e4a3860", checksumkind: CSK_MD5, checksum: "7165aec212fc528edf645f7f5c1c91bb")
+!30 = !DINamespace(name: "FnOnce", scope: !31)
+!31 = !DINamespace(name: "function", scope: !32)
+!32 = !DINamespace(name: "ops", scope: !33)
+!33 = !DINamespace(name: "core", scope: null)
+!34 = distinct !DILocation(line: 166, column: 18, scope: !23)
+!35 = !DILocation(line: 491, column: 5, scope: !36, inlinedAt: !39)
+!36 = distinct !DISubprogram(name: "black_box<()>", linkageName: "_ZN4core4hint9black_box17h326433cef1a47656E", scope: !38, file: !37, line: 490, type: !12, scopeLine: 490, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!37 = !DIFile(filename: "library/core/src/hint.rs", directory: "/rustc/59807616e1fa2540724bfbac14d7976d7e4a3860", checksumkind: CSK_MD5, checksum: "2a901a283eb5f69871d8be07d256ca78")
+!38 = !DINamespace(name: "hint", scope: !33)
+!39 = !DILocation(line: 169, column: 5, scope: !40)
+!40 = distinct !DILexicalBlock(scope: !23, file: !24, line: 166, column: 5)
+!41 = !{i64 15603813758496627}
+!42 = !DILocation(line: 172, column: 2, scope: !23)
+!43 = distinct !DISubprogram(name: "call_once<std::rt::lang_start::{closure_env#0}<()>, ()>", linkageName: "_ZN4core3ops8function6FnOnce40call_once$u7b$$u7b$vtable.shim$u7d$$u7d$17h3817abf4a24d750dE", scope: !30, file: !29, line: 250, type: !12, scopeLine: 250, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!44 = !DILocation(line: 250, column: 5, scope: !43)
+!45 = !DILocation(line: 206, column: 18, scope: !18, inlinedAt: !46)
+!46 = distinct !DILocation(line: 250, column: 5, scope: !47, inlinedAt: !48)
+!47 = distinct !DISubprogram(name: "call_once<std::rt::lang_start::{closure_env#0}<()>, ()>", linkageName: "_ZN4core3ops8function6FnOnce9call_once17h4b399a2a0db74279E", scope: !30, file: !29, line: 250, type: !12, scopeLine: 250, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!48 = distinct !DILocation(line: 250, column: 5, scope: !43)
+!49 = !{!50}
+!50 = distinct !{!50, !51, !"_ZN3std2rt10lang_start28_$u7b$$u7b$closure$u7d$$u7d$17h0ae11af4798a1a5cE: %_1"}
+!51 = distinct !{!51, !"_ZN3std2rt10lang_start28_$u7b$$u7b$closure$u7d$$u7d$17h0ae11af4798a1a5cE"}
+!52 = distinct !DISubprogram(name: "drop_in_place<std::env::Args>", linkageName: "_ZN4core3ptr35drop_in_place$LT$std..env..Args$GT$17heb1638584899d53eE", scope: !54, file: !53, line: 805, type: !12, scopeLine: 805, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!53 = !DIFile(filename: "library/core/src/ptr/mod.rs", directory: "/rustc/59807616e1fa2540724bfbac14d7976d7e4a3860", checksumkind: CSK_MD5, checksum: "8f706ae0a0620120e6e931983ef127f1")
+!54 = !DINamespace(name: "ptr", scope: !33)
+!55 = !{!56}
+!56 = distinct !{!56, !57, !"_ZN4core3ptr37drop_in_place$LT$std..env..ArgsOs$GT$17ha61acf9dba83f8caE: %_1"}
+!57 = distinct !{!57, !"_ZN4core3ptr37drop_in_place$LT$std..env..ArgsOs$GT$17ha61acf9dba83f8caE"}
+!58 = !DILocation(line: 805, column: 1, scope: !52)
+!59 = !{!60}
+!60 = distinct !{!60, !61, !"_ZN4core3ptr49drop_in_place$LT$std..sys..args..common..Args$GT$17h4dbda4b213b32426E: %_1"}
+!61 = distinct !{!61, !"_ZN4core3ptr49drop_in_place$LT$std..sys..args..common..Args$GT$17h4dbda4b213b32426E"}
+!62 = !DILocation(line: 805, column: 1, scope: !63, inlinedAt: !64)
+!63 = distinct !DISubprogram(name: "drop_in_place<std::env::ArgsOs>", linkageName: "_ZN4core3ptr37drop_in_place$LT$std..env..ArgsOs$GT$17ha61acf9dba83f8caE", scope: !54, file: !53, line: 805, type: !12, scopeLine: 805, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!64 = distinct !DILocation(line: 805, column: 1, scope: !52)
+!65 = !{!66}
+!66 = distinct !{!66, !67, !"_ZN4core3ptr86drop_in_place$LT$alloc..vec..into_iter..IntoIter$LT$std..ffi..os_str..OsString$GT$$GT$17h8f5f2cd2b92ae635E: %_1"}
+!67 = distinct !{!67, !"_ZN4core3ptr86drop_in_place$LT$alloc..vec..into_iter..IntoIter$LT$std..ffi..os_str..OsString$GT$$GT$17h8f5f2cd2b92ae635E"}
+!68 = !DILocation(line: 805, column: 1, scope: !69, inlinedAt: !70)
+!69 = distinct !DISubprogram(name: "drop_in_place<std::sys::args::common::Args>", linkageName: "_ZN4core3ptr49drop_in_place$LT$std..sys..args..common..Args$GT$17h4dbda4b213b32426E", scope: !54, file: !53, line: 805, type: !12, scopeLine: 805, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!70 = distinct !DILocation(line: 805, column: 1, scope: !63, inlinedAt: !64)
+!71 = !{!72}
+!72 = distinct !{!72, !73, !"_ZN86_$LT$alloc..vec..into_iter..IntoIter$LT$T$C$A$GT$$u20$as$u20$core..ops..drop..Drop$GT$4drop17h7ee1c5c2312eb06bE: %self"}
+!73 = distinct !{!73, !"_ZN86_$LT$alloc..vec..into_iter..IntoIter$LT$T$C$A$GT$$u20$as$u20$core..ops..drop..Drop$GT$4drop17h7ee1c5c2312eb06bE"}
+!74 = !DILocation(line: 805, column: 1, scope: !75, inlinedAt: !76)
+!75 = distinct !DISubprogram(name: "drop_in_place<alloc::vec::into_iter::IntoIter<std::ffi::os_str::OsString, alloc::alloc::Global>>", linkageName: "_ZN4core3ptr86drop_in_place$LT$alloc..vec..into_iter..IntoIter$LT$std..ffi..os_str..OsString$GT$$GT$17h8f5f2cd2b92ae635E", scope: !54, file: !53, line: 805, type: !12, scopeLine: 805, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!76 = distinct !DILocation(line: 805, column: 1, scope: !69, inlinedAt: !70)
+!77 = !DILocation(line: 514, column: 40, scope: !78, inlinedAt: !85)
+!78 = distinct !DILexicalBlock(scope: !80, file: !79, line: 511, column: 9)
+!79 = !DIFile(filename: "library/alloc/src/vec/into_iter.rs", directory: "/rustc/59807616e1fa2540724bfbac14d7976d7e4a3860", checksumkind: CSK_MD5, checksum: "67afe9548c17103ef643b1b8003217a3")
+!80 = distinct !DISubprogram(name: "drop<std::ffi::os_str::OsString, alloc::alloc::Global>", linkageName: "_ZN86_$LT$alloc..vec..into_iter..IntoIter$LT$T$C$A$GT$$u20$as$u20$core..ops..drop..Drop$GT$4drop17h7ee1c5c2312eb06bE", scope: !81, file: !79, line: 497, type: !12, scopeLine: 497, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!81 = !DINamespace(name: "{impl#16}", scope: !82)
+!82 = !DINamespace(name: "into_iter", scope: !83)
+!83 = !DINamespace(name: "vec", scope: !84)
+!84 = !DINamespace(name: "alloc", scope: null)
+!85 = distinct !DILocation(line: 805, column: 1, scope: !75, inlinedAt: !76)
+!86 = !{!72, !66, !60, !56}
+!87 = !DILocation(line: 729, column: 18, scope: !88, inlinedAt: !93)
+!88 = distinct !DILexicalBlock(scope: !90, file: !89, line: 726, column: 9)
+!89 = !DIFile(filename: "library/core/src/ptr/const_ptr.rs", directory: "/rustc/59807616e1fa2540724bfbac14d7976d7e4a3860", checksumkind: CSK_MD5, checksum: "48638a38fe414ca30cde797e4a40b3a9")
+!90 = distinct !DISubprogram(name: "offset_from_unsigned<std::ffi::os_str::OsString>", linkageName: "_ZN4core3ptr9const_ptr33_$LT$impl$u20$$BP$const$u20$T$GT$20offset_from_unsigned17h5ee7d2bf6009c249E", scope: !91, file: !89, line: 701, type: !12, scopeLine: 701, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!91 = !DINamespace(name: "{impl#0}", scope: !92)
+!92 = !DINamespace(name: "const_ptr", scope: !54)
+!93 = distinct !DILocation(line: 887, column: 37, scope: !94, inlinedAt: !98)
+!94 = distinct !DISubprogram(name: "offset_from_unsigned<std::ffi::os_str::OsString>", linkageName: "_ZN4core3ptr7mut_ptr31_$LT$impl$u20$$BP$mut$u20$T$GT$20offset_from_unsigned17h86b27e5f24c3f269E", scope: !96, file: !95, line: 882, type: !12, scopeLine: 882, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!95 = !DIFile(filename: "library/core/src/ptr/mut_ptr.rs", directory: "/rustc/59807616e1fa2540724bfbac14d7976d7e4a3860", checksumkind: CSK_MD5, checksum: "7c03c8e0ae695174f7f10881dc49d2cb")
+!96 = !DINamespace(name: "{impl#0}", scope: !97)
+!97 = !DINamespace(name: "mut_ptr", scope: !54)
+!98 = distinct !DILocation(line: 953, column: 32, scope: !99, inlinedAt: !103)
+!99 = distinct !DISubprogram(name: "offset_from_unsigned<std::ffi::os_str::OsString>", linkageName: "_ZN4core3ptr8non_null16NonNull$LT$T$GT$20offset_from_unsigned17h7de95ffb5caa9ac1E", scope: !101, file: !100, line: 948, type: !12, scopeLine: 948, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!100 = !DIFile(filename: "library/core/src/ptr/non_null.rs", directory: "/rustc/59807616e1fa2540724bfbac14d7976d7e4a3860", checksumkind: CSK_MD5, checksum: "e5222a5160165ba1cd09076ad7a11733")
+!101 = !DINamespace(name: "NonNull", scope: !102)
+!102 = !DINamespace(name: "non_null", scope: !54)
+!103 = distinct !DILocation(line: 238, column: 45, scope: !104, inlinedAt: !106)
+!104 = distinct !DISubprogram(name: "size_hint<std::ffi::os_str::OsString, alloc::alloc::Global>", linkageName: "_ZN103_$LT$alloc..vec..into_iter..IntoIter$LT$T$C$A$GT$$u20$as$u20$core..iter..traits..iterator..Iterator$GT$9size_hint17h0c2a4a0286310aa5E", scope: !105, file: !79, line: 234, type: !12, scopeLine: 234, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!105 = !DINamespace(name: "{impl#6}", scope: !82)
+!106 = distinct !DILocation(line: 117, column: 35, scope: !107, inlinedAt: !113)
+!107 = distinct !DISubprogram(name: "len<alloc::vec::into_iter::IntoIter<std::ffi::os_str::OsString, alloc::alloc::Global>>", linkageName: "_ZN4core4iter6traits10exact_size17ExactSizeIterator3len17h462848082fd6c29eE", scope: !109, file: !108, line: 116, type: !12, scopeLine: 116, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!108 = !DIFile(filename: "library/core/src/iter/traits/exact_size.rs", directory: "/rustc/59807616e1fa2540724bfbac14d7976d7e4a3860", checksumkind: CSK_MD5, checksum: "b65df84932b5452054a09996bbfca6e2")
+!109 = !DINamespace(name: "ExactSizeIterator", scope: !110)
+!110 = !DINamespace(name: "exact_size", scope: !111)
+!111 = !DINamespace(name: "traits", scope: !112)
+!112 = !DINamespace(name: "iter", scope: !33)
+!113 = distinct !DILocation(line: 156, column: 18, scope: !114, inlinedAt: !116)
+!114 = distinct !DISubprogram(name: "len<alloc::vec::into_iter::IntoIter<std::ffi::os_str::OsString, alloc::alloc::Global>>", linkageName: "_ZN83_$LT$$RF$mut$u20$I$u20$as$u20$core..iter..traits..exact_size..ExactSizeIterator$GT$3len17h60d2567a6ea315aeE", scope: !115, file: !108, line: 155, type: !12, scopeLine: 155, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!115 = !DINamespace(name: "{impl#0}", scope: !110)
+!116 = distinct !DILocation(line: 118, column: 63, scope: !117, inlinedAt: !119)
+!117 = distinct !DISubprogram(name: "as_raw_mut_slice<std::ffi::os_str::OsString, alloc::alloc::Global>", linkageName: "_ZN5alloc3vec9into_iter21IntoIter$LT$T$C$A$GT$16as_raw_mut_slice17h0961a6a4e93bd199E", scope: !118, file: !79, line: 117, type: !12, scopeLine: 117, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!118 = !DINamespace(name: "IntoIter", scope: !82)
+!119 = distinct !DILocation(line: 514, column: 40, scope: !78, inlinedAt: !85)
+!120 = !{!121}
+!121 = distinct !{!121, !122, !"_ZN4core3ptr57drop_in_place$LT$$u5b$std..ffi..os_str..OsString$u5d$$GT$17h259706cd7e6db349E: %_1.0"}
+!122 = distinct !{!122, !"_ZN4core3ptr57drop_in_place$LT$$u5b$std..ffi..os_str..OsString$u5d$$GT$17h259706cd7e6db349E"}
+!123 = !DILocation(line: 514, column: 13, scope: !78, inlinedAt: !85)
+!124 = !DILocation(line: 805, column: 1, scope: !125, inlinedAt: !126)
+!125 = distinct !DISubprogram(name: "drop_in_place<[std::ffi::os_str::OsString]>", linkageName: "_ZN4core3ptr57drop_in_place$LT$$u5b$std..ffi..os_str..OsString$u5d$$GT$17h259706cd7e6db349E", scope: !54, file: !53, line: 805, type: !12, scopeLine: 805, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!126 = distinct !DILocation(line: 514, column: 13, scope: !78, inlinedAt: !85)
+!127 = !DILocation(line: 632, column: 39, scope: !128, inlinedAt: !132)
+!128 = distinct !DISubprogram(name: "current_memory<alloc::alloc::Global>", linkageName: "_ZN5alloc7raw_vec20RawVecInner$LT$A$GT$14current_memory17h175f73c9709c519eE", scope: !130, file: !129, line: 631, type: !12, scopeLine: 631, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!129 = !DIFile(filename: "library/alloc/src/raw_vec/mod.rs", directory: "/rustc/59807616e1fa2540724bfbac14d7976d7e4a3860", checksumkind: CSK_MD5, checksum: "78584250230dfe0aae92794a07566ae7")
+!130 = !DINamespace(name: "RawVecInner", scope: !131)
+!131 = !DINamespace(name: "raw_vec", scope: !84)
+!132 = distinct !DILocation(line: 872, column: 52, scope: !133, inlinedAt: !135)
+!133 = distinct !DILexicalBlock(scope: !134, file: !129, line: 872, column: 82)
+!134 = distinct !DISubprogram(name: "deallocate<alloc::alloc::Global>", linkageName: "_ZN5alloc7raw_vec20RawVecInner$LT$A$GT$10deallocate17h3d490affe32df9d8E", scope: !130, file: !129, line: 870, type: !12, scopeLine: 870, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!135 = distinct !DILocation(line: 423, column: 29, scope: !136, inlinedAt: !138)
+!136 = distinct !DISubprogram(name: "drop<u8, alloc::alloc::Global>", linkageName: "_ZN77_$LT$alloc..raw_vec..RawVec$LT$T$C$A$GT$$u20$as$u20$core..ops..drop..Drop$GT$4drop17h8106b8696e189258E", scope: !137, file: !129, line: 421, type: !12, scopeLine: 421, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!137 = !DINamespace(name: "{impl#3}", scope: !131)
+!138 = distinct !DILocation(line: 805, column: 1, scope: !139, inlinedAt: !140)
+!139 = distinct !DISubprogram(name: "drop_in_place<alloc::raw_vec::RawVec<u8, alloc::alloc::Global>>", linkageName: "_ZN4core3ptr53drop_in_place$LT$alloc..raw_vec..RawVec$LT$u8$GT$$GT$17h91746a64644f18a2E", scope: !54, file: !53, line: 805, type: !12, scopeLine: 805, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!140 = distinct !DILocation(line: 805, column: 1, scope: !141, inlinedAt: !142)
+!141 = distinct !DISubprogram(name: "drop_in_place<alloc::vec::Vec<u8, alloc::alloc::Global>>", linkageName: "_ZN4core3ptr46drop_in_place$LT$alloc..vec..Vec$LT$u8$GT$$GT$17h2fb4cbe4acb651f8E", scope: !54, file: !53, line: 805, type: !12, scopeLine: 805, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!142 = distinct !DILocation(line: 805, column: 1, scope: !143, inlinedAt: !144)
+!143 = distinct !DISubprogram(name: "drop_in_place<std::sys::os_str::bytes::Buf>", linkageName: "_ZN4core3ptr49drop_in_place$LT$std..sys..os_str..bytes..Buf$GT$17h70293914bbdc6179E", scope: !54, file: !53, line: 805, type: !12, scopeLine: 805, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!144 = distinct !DILocation(line: 805, column: 1, scope: !145, inlinedAt: !146)
+!145 = distinct !DISubprogram(name: "drop_in_place<std::ffi::os_str::OsString>", linkageName: "_ZN4core3ptr47drop_in_place$LT$std..ffi..os_str..OsString$GT$17h313d29781e168e72E", scope: !54, file: !53, line: 805, type: !12, scopeLine: 805, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!146 = distinct !DILocation(line: 805, column: 1, scope: !125, inlinedAt: !126)
+!147 = !DILocation(line: 115, column: 14, scope: !148, inlinedAt: !151)
+!148 = distinct !DISubprogram(name: "dealloc", linkageName: "_RNvNtCslNYArtu3iFV_5alloc5alloc7dealloc", scope: !150, file: !149, line: 114, type: !12, scopeLine: 114, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!149 = !DIFile(filename: "library/alloc/src/alloc.rs", directory: "/rustc/59807616e1fa2540724bfbac14d7976d7e4a3860", checksumkind: CSK_MD5, checksum: "57066d22da38e4b1f3bfd29f3d5ff5ae")
+!150 = !DINamespace(name: "alloc", scope: !84)
+!151 = distinct !DILocation(line: 209, column: 22, scope: !152, inlinedAt: !154)
+!152 = distinct !DISubprogram(name: "deallocate_impl_runtime", linkageName: "_RNvMNtCslNYArtu3iFV_5alloc5allocNtB2_6Global23deallocate_impl_runtime", scope: !153, file: !149, line: 199, type: !12, scopeLine: 199, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!153 = !DINamespace(name: "Global", scope: !150)
+!154 = distinct !DILocation(line: 324, column: 9, scope: !155, inlinedAt: !156)
+!155 = distinct !DISubprogram(name: "deallocate_impl", linkageName: "_RNvMNtCslNYArtu3iFV_5alloc5allocNtB2_6Global15deallocate_impl", scope: !153, file: !149, line: 323, type: !12, scopeLine: 323, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!156 = distinct !DILocation(line: 442, column: 23, scope: !157, inlinedAt: !159)
+!157 = distinct !DISubprogram(name: "deallocate", linkageName: "_RNvXs_NtCslNYArtu3iFV_5alloc5allocNtB4_6GlobalNtNtCsgEmfK2I1SDS_4core5alloc9Allocator10deallocate", scope: !158, file: !149, line: 440, type: !12, scopeLine: 440, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!158 = !DINamespace(name: "{impl#1}", scope: !150)
+!159 = distinct !DILocation(line: 874, column: 28, scope: !133, inlinedAt: !135)
+!160 = !{!121, !72, !66, !60, !56}
+!161 = !DILocation(line: 200, column: 9, scope: !152, inlinedAt: !154)
+!162 = !DILocation(line: 506, column: 65, scope: !163, inlinedAt: !167)
+!163 = distinct !DILexicalBlock(scope: !164, file: !79, line: 504, column: 21)
+!164 = distinct !DISubprogram(name: "drop<std::ffi::os_str::OsString, alloc::alloc::Global>", linkageName: "_ZN157_$LT$$LT$alloc..vec..into_iter..IntoIter$LT$T$C$A$GT$$u20$as$u20$core..ops..drop..Drop$GT$..drop..DropGuard$LT$T$C$A$GT$$u20$as$u20$core..ops..drop..Drop$GT$4drop17hb9223d168f99c375E", scope: !165, file: !79, line: 501, type: !12, scopeLine: 501, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!165 = !DINamespace(name: "{impl#0}", scope: !166)
+!166 = !DINamespace(name: "drop", scope: !81)
+!167 = distinct !DILocation(line: 805, column: 1, scope: !168, inlinedAt: !169)
+!168 = distinct !DISubprogram(name: "drop_in_place<alloc::vec::into_iter::{impl#16}::drop::DropGuard<std::ffi::os_str::OsString, alloc::alloc::Global>>", linkageName: "_ZN4core3ptr180drop_in_place$LT$$LT$alloc..vec..into_iter..IntoIter$LT$T$C$A$GT$$u20$as$u20$core..ops..drop..Drop$GT$..drop..DropGuard$LT$std..ffi..os_str..OsString$C$alloc..alloc..Global$GT$$GT$17h726bf51b6467a630E", scope: !54, file: !53, line: 805, type: !12, scopeLine: 805, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!169 = distinct !DILocation(line: 517, column: 5, scope: !80, inlinedAt: !85)
+!170 = !DILocation(line: 632, column: 39, scope: !128, inlinedAt: !171)
+!171 = distinct !DILocation(line: 872, column: 52, scope: !133, inlinedAt: !172)
+!172 = distinct !DILocation(line: 423, column: 29, scope: !173, inlinedAt: !174)
+!173 = distinct !DISubprogram(name: "drop<std::ffi::os_str::OsString, alloc::alloc::Global>", linkageName: "_ZN77_$LT$alloc..raw_vec..RawVec$LT$T$C$A$GT$$u20$as$u20$core..ops..drop..Drop$GT$4drop17h3f4f459a28d29254E", scope: !137, file: !129, line: 421, type: !12, scopeLine: 421, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!174 = distinct !DILocation(line: 805, column: 1, scope: !175, inlinedAt: !176)
+!175 = distinct !DISubprogram(name: "drop_in_place<alloc::raw_vec::RawVec<std::ffi::os_str::OsString, alloc::alloc::Global>>", linkageName: "_ZN4core3ptr77drop_in_place$LT$alloc..raw_vec..RawVec$LT$std..ffi..os_str..OsString$GT$$GT$17h3be5a1937e035728E", scope: !54, file: !53, line: 805, type: !12, scopeLine: 805, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!176 = distinct !DILocation(line: 506, column: 83, scope: !163, inlinedAt: !167)
+!177 = !DILocation(line: 506, column: 53, scope: !163, inlinedAt: !167)
+!178 = !DILocation(line: 1232, column: 17, scope: !179, inlinedAt: !183)
+!179 = distinct !DISubprogram(name: "unchecked_mul", linkageName: "_RNvMs9_NtCsgEmfK2I1SDS_4core3numj13unchecked_mul", scope: !181, file: !180, line: 1220, type: !12, scopeLine: 1220, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!180 = !DIFile(filename: "library/core/src/num/uint_macros.rs", directory: "/rustc/59807616e1fa2540724bfbac14d7976d7e4a3860", checksumkind: CSK_MD5, checksum: "de2f29a3485fc28d710ba6c3062e6efa")
+!181 = !DINamespace(name: "{impl#11}", scope: !182)
+!182 = !DINamespace(name: "num", scope: !33)
+!183 = distinct !DILocation(line: 640, column: 53, scope: !128, inlinedAt: !171)
+!184 = !DILocation(line: 115, column: 14, scope: !148, inlinedAt: !185)
+!185 = distinct !DILocation(line: 209, column: 22, scope: !152, inlinedAt: !186)
+!186 = distinct !DILocation(line: 324, column: 9, scope: !155, inlinedAt: !187)
+!187 = distinct !DILocation(line: 442, column: 23, scope: !157, inlinedAt: !188)
+!188 = distinct !DILocation(line: 874, column: 28, scope: !133, inlinedAt: !172)
+!189 = !DILocation(line: 200, column: 9, scope: !152, inlinedAt: !186)
+!190 = distinct !DISubprogram(name: "fold<std::env::Args, usize, core::iter::traits::iterator::Iterator::count::{closure_env#0}<std::env::Args>>", linkageName: "_ZN4core4iter6traits8iterator8Iterator4fold17hb1eeeb7fe1755506E", scope: !192, file: !191, line: 2640, type: !194, scopeLine: 2640, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!191 = !DIFile(filename: "library/core/src/iter/traits/iterator.rs", directory: "/rustc/59807616e1fa2540724bfbac14d7976d7e4a3860", checksumkind: CSK_MD5, checksum: "03f316921fc0b2bb7786dd4fa41456f9")
+!192 = !DINamespace(name: "Iterator", scope: !193)
+!193 = !DINamespace(name: "iterator", scope: !111)
+!194 = !DISubroutineType(cc: DW_CC_nocall, types: !13)
+!195 = !DILocation(line: 2646, column: 9, scope: !196)
+!196 = distinct !DILexicalBlock(scope: !190, file: !191, line: 2645, column: 9)
+!197 = !DILocation(line: 0, scope: !190)
+!198 = !DILocation(line: 2646, column: 29, scope: !199)
+!199 = distinct !DILexicalBlock(scope: !196, file: !191, line: 2646, column: 41)
+!200 = !DILocation(line: 2646, column: 34, scope: !199)
+!201 = !DILocation(line: 2650, column: 5, scope: !190)
+!202 = !DILocation(line: 2640, column: 5, scope: !190)
+!203 = !{i64 0, i64 -9223372036854775807}
+!204 = !DILocation(line: 2646, column: 19, scope: !199)
+!205 = !DILocation(line: 2646, column: 24, scope: !199)
+!206 = !DILocation(line: 115, column: 14, scope: !148, inlinedAt: !207)
+!207 = distinct !DILocation(line: 209, column: 22, scope: !152, inlinedAt: !208)
+!208 = distinct !DILocation(line: 324, column: 9, scope: !155, inlinedAt: !209)
+!209 = distinct !DILocation(line: 442, column: 23, scope: !157, inlinedAt: !210)
+!210 = distinct !DILocation(line: 874, column: 28, scope: !133, inlinedAt: !211)
+!211 = distinct !DILocation(line: 423, column: 29, scope: !136, inlinedAt: !212)
+!212 = distinct !DILocation(line: 805, column: 1, scope: !139, inlinedAt: !213)
+!213 = distinct !DILocation(line: 805, column: 1, scope: !141, inlinedAt: !214)
+!214 = distinct !DILocation(line: 805, column: 1, scope: !215, inlinedAt: !216)
+!215 = distinct !DISubprogram(name: "drop_in_place<alloc::string::String>", linkageName: "_ZN4core3ptr42drop_in_place$LT$alloc..string..String$GT$17h9b153db62b563c81E", scope: !54, file: !53, line: 805, type: !12, scopeLine: 805, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!216 = distinct !DILocation(line: 232, column: 32, scope: !217, inlinedAt: !219)
+!217 = distinct !DISubprogram(name: "{closure#0}<std::env::Args>", linkageName: "_ZN4core4iter6traits8iterator8Iterator5count28_$u7b$$u7b$closure$u7d$$u7d$17hbb4e67c61d566b59E", scope: !218, file: !191, line: 232, type: !12, scopeLine: 232, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!218 = !DINamespace(name: "count", scope: !192)
+!219 = distinct !DILocation(line: 2647, column: 21, scope: !199)
+!220 = !DILocation(line: 200, column: 9, scope: !152, inlinedAt: !208)
+!221 = !DILocation(line: 2648, column: 9, scope: !196)
+!222 = !{!223}
+!223 = distinct !{!223, !224, !"_ZN4core3ptr35drop_in_place$LT$std..env..Args$GT$17heb1638584899d53eE: %_1"}
+!224 = distinct !{!224, !"_ZN4core3ptr35drop_in_place$LT$std..env..Args$GT$17heb1638584899d53eE"}
+!225 = !{!226}
+!226 = distinct !{!226, !227, !"_ZN4core3ptr37drop_in_place$LT$std..env..ArgsOs$GT$17ha61acf9dba83f8caE: %_1"}
+!227 = distinct !{!227, !"_ZN4core3ptr37drop_in_place$LT$std..env..ArgsOs$GT$17ha61acf9dba83f8caE"}
+!228 = !DILocation(line: 805, column: 1, scope: !52, inlinedAt: !229)
+!229 = distinct !DILocation(line: 2650, column: 5, scope: !190)
+!230 = !{!231}
+!231 = distinct !{!231, !232, !"_ZN4core3ptr49drop_in_place$LT$std..sys..args..common..Args$GT$17h4dbda4b213b32426E: %_1"}
+!232 = distinct !{!232, !"_ZN4core3ptr49drop_in_place$LT$std..sys..args..common..Args$GT$17h4dbda4b213b32426E"}
+!233 = !DILocation(line: 805, column: 1, scope: !63, inlinedAt: !234)
+!234 = distinct !DILocation(line: 805, column: 1, scope: !52, inlinedAt: !229)
+!235 = !{!236}
+!236 = distinct !{!236, !237, !"_ZN4core3ptr86drop_in_place$LT$alloc..vec..into_iter..IntoIter$LT$std..ffi..os_str..OsString$GT$$GT$17h8f5f2cd2b92ae635E: %_1"}
+!237 = distinct !{!237, !"_ZN4core3ptr86drop_in_place$LT$alloc..vec..into_iter..IntoIter$LT$std..ffi..os_str..OsString$GT$$GT$17h8f5f2cd2b92ae635E"}
+!238 = !DILocation(line: 805, column: 1, scope: !69, inlinedAt: !239)
+!239 = distinct !DILocation(line: 805, column: 1, scope: !63, inlinedAt: !234)
+!240 = !{!241}
+!241 = distinct !{!241, !242, !"_ZN86_$LT$alloc..vec..into_iter..IntoIter$LT$T$C$A$GT$$u20$as$u20$core..ops..drop..Drop$GT$4drop17h7ee1c5c2312eb06bE: %self"}
+!242 = distinct !{!242, !"_ZN86_$LT$alloc..vec..into_iter..IntoIter$LT$T$C$A$GT$$u20$as$u20$core..ops..drop..Drop$GT$4drop17h7ee1c5c2312eb06bE"}
+!243 = !DILocation(line: 805, column: 1, scope: !75, inlinedAt: !244)
+!244 = distinct !DILocation(line: 805, column: 1, scope: !69, inlinedAt: !239)
+!245 = !DILocation(line: 514, column: 40, scope: !78, inlinedAt: !246)
+!246 = distinct !DILocation(line: 805, column: 1, scope: !75, inlinedAt: !244)
+!247 = !{!241, !236, !231, !226, !223}
+!248 = !DILocation(line: 729, column: 18, scope: !88, inlinedAt: !249)
+!249 = distinct !DILocation(line: 887, column: 37, scope: !94, inlinedAt: !250)
+!250 = distinct !DILocation(line: 953, column: 32, scope: !99, inlinedAt: !251)
+!251 = distinct !DILocation(line: 238, column: 45, scope: !104, inlinedAt: !252)
+!252 = distinct !DILocation(line: 117, column: 35, scope: !107, inlinedAt: !253)
+!253 = distinct !DILocation(line: 156, column: 18, scope: !114, inlinedAt: !254)
+!254 = distinct !DILocation(line: 118, column: 63, scope: !117, inlinedAt: !255)
+!255 = distinct !DILocation(line: 514, column: 40, scope: !78, inlinedAt: !246)
+!256 = !{!257}
+!257 = distinct !{!257, !258, !"_ZN4core3ptr57drop_in_place$LT$$u5b$std..ffi..os_str..OsString$u5d$$GT$17h259706cd7e6db349E: %_1.0"}
+!258 = distinct !{!258, !"_ZN4core3ptr57drop_in_place$LT$$u5b$std..ffi..os_str..OsString$u5d$$GT$17h259706cd7e6db349E"}
+!259 = !DILocation(line: 514, column: 13, scope: !78, inlinedAt: !246)
+!260 = !DILocation(line: 805, column: 1, scope: !125, inlinedAt: !261)
+!261 = distinct !DILocation(line: 514, column: 13, scope: !78, inlinedAt: !246)
+!262 = !DILocation(line: 632, column: 39, scope: !128, inlinedAt: !263)
+!263 = distinct !DILocation(line: 872, column: 52, scope: !133, inlinedAt: !264)
+!264 = distinct !DILocation(line: 423, column: 29, scope: !136, inlinedAt: !265)
+!265 = distinct !DILocation(line: 805, column: 1, scope: !139, inlinedAt: !266)
+!266 = distinct !DILocation(line: 805, column: 1, scope: !141, inlinedAt: !267)
+!267 = distinct !DILocation(line: 805, column: 1, scope: !143, inlinedAt: !268)
+!268 = distinct !DILocation(line: 805, column: 1, scope: !145, inlinedAt: !269)
+!269 = distinct !DILocation(line: 805, column: 1, scope: !125, inlinedAt: !261)
+!270 = !DILocation(line: 115, column: 14, scope: !148, inlinedAt: !271)
+!271 = distinct !DILocation(line: 209, column: 22, scope: !152, inlinedAt: !272)
+!272 = distinct !DILocation(line: 324, column: 9, scope: !155, inlinedAt: !273)
+!273 = distinct !DILocation(line: 442, column: 23, scope: !157, inlinedAt: !274)
+!274 = distinct !DILocation(line: 874, column: 28, scope: !133, inlinedAt: !264)
+!275 = !{!257, !241, !236, !231, !226, !223}
+!276 = !DILocation(line: 200, column: 9, scope: !152, inlinedAt: !272)
+!277 = !DILocation(line: 506, column: 65, scope: !163, inlinedAt: !278)
+!278 = distinct !DILocation(line: 805, column: 1, scope: !168, inlinedAt: !279)
+!279 = distinct !DILocation(line: 517, column: 5, scope: !80, inlinedAt: !246)
+!280 = !DILocation(line: 632, column: 39, scope: !128, inlinedAt: !281)
+!281 = distinct !DILocation(line: 872, column: 52, scope: !133, inlinedAt: !282)
+!282 = distinct !DILocation(line: 423, column: 29, scope: !173, inlinedAt: !283)
+!283 = distinct !DILocation(line: 805, column: 1, scope: !175, inlinedAt: !284)
+!284 = distinct !DILocation(line: 506, column: 83, scope: !163, inlinedAt: !278)
+!285 = !DILocation(line: 506, column: 53, scope: !163, inlinedAt: !278)
+!286 = !DILocation(line: 1232, column: 17, scope: !179, inlinedAt: !287)
+!287 = distinct !DILocation(line: 640, column: 53, scope: !128, inlinedAt: !281)
+!288 = !DILocation(line: 115, column: 14, scope: !148, inlinedAt: !289)
+!289 = distinct !DILocation(line: 209, column: 22, scope: !152, inlinedAt: !290)
+!290 = distinct !DILocation(line: 324, column: 9, scope: !155, inlinedAt: !291)
+!291 = distinct !DILocation(line: 442, column: 23, scope: !157, inlinedAt: !292)
+!292 = distinct !DILocation(line: 874, column: 28, scope: !133, inlinedAt: !282)
+!293 = !DILocation(line: 200, column: 9, scope: !152, inlinedAt: !290)
+!294 = !DILocation(line: 2650, column: 6, scope: !190)
+!295 = !DILocation(line: 232, column: 24, scope: !217, inlinedAt: !219)
+!296 = distinct !DISubprogram(name: "demo", linkageName: "_ZN7explain4demo17h13e83f3bf86c2519E", scope: !298, file: !297, line: 12, type: !194, scopeLine: 12, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!297 = !DIFile(filename: "src/main.rs", directory: "/explain", checksumkind: CSK_MD5, checksum: "23fef91cb0c455ead2f1df8701c233a3")
+!298 = !DINamespace(name: "explain", scope: null)
+!299 = !DILocation(line: 9, column: 5, scope: !300, inlinedAt: !301)
+!300 = distinct !DISubprogram(name: "middle", linkageName: "_ZN7explain6middle17hcfa5b3b4a384273cE", scope: !298, file: !297, line: 8, type: !12, scopeLine: 8, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!301 = distinct !DILocation(line: 14, column: 13, scope: !302, inlinedAt: !306)
+!302 = distinct !DILexicalBlock(scope: !303, file: !297, line: 12, column: 1)
+!303 = distinct !DILexicalBlock(scope: !304, file: !297, line: 12, column: 1)
+!304 = distinct !DISubprogram(name: "{closure#0}", linkageName: "_ZN7explain4demo28_$u7b$$u7b$closure$u7d$$u7d$17hccfb7de2b737dbd0E", scope: !305, file: !297, line: 12, type: !12, scopeLine: 12, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!305 = !DINamespace(name: "demo", scope: !298)
+!306 = !DILocation(line: 12, column: 1, scope: !307)
+!307 = distinct !DILexicalBlock(scope: !296, file: !297, line: 12, column: 1)
+!308 = !DILocation(line: 12, column: 1, scope: !309, inlinedAt: !311)
+!309 = distinct !DISubprogram(name: "drop", linkageName: "_ZN66_$LT$explain..demo..__NoPanic$u20$as$u20$core..ops..drop..Drop$GT$4drop17hf070b104fe0dd7e8E", scope: !310, file: !297, line: 12, type: !12, scopeLine: 12, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!310 = !DINamespace(name: "{impl#0}", scope: !305)
+!311 = distinct !DILocation(line: 805, column: 1, scope: !312, inlinedAt: !313)
+!312 = distinct !DISubprogram(name: "drop_in_place<explain::demo::__NoPanic>", linkageName: "_ZN4core3ptr45drop_in_place$LT$explain..demo..__NoPanic$GT$17hcf8b42e64d401220E", scope: !54, file: !53, line: 805, type: !12, scopeLine: 805, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!313 = distinct !DILocation(line: 12, column: 11, scope: !296)
+!314 = !DILocation(line: 2457, column: 13, scope: !315, inlinedAt: !317)
+!315 = distinct !DISubprogram(name: "wrapping_add", linkageName: "_RNvMs6_NtCsgEmfK2I1SDS_4core3numm12wrapping_add", scope: !316, file: !180, line: 2456, type: !12, scopeLine: 2456, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!316 = !DINamespace(name: "{impl#8}", scope: !182)
+!317 = !DILocation(line: 15, column: 7, scope: !318, inlinedAt: !306)
+!318 = distinct !DILexicalBlock(scope: !302, file: !297, line: 12, column: 1)
+!319 = !DILocation(line: 12, column: 12, scope: !296)
+!320 = distinct !DISubprogram(name: "main", linkageName: "_ZN7explain4main17h14301b2c4ccd26f8E", scope: !298, file: !297, line: 18, type: !12, scopeLine: 18, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized | DISPFlagMainSubprogram, unit: !6, templateParams: !13)
+!321 = !DILocation(line: 19, column: 13, scope: !320)
+!322 = !DILocation(line: 229, column: 14, scope: !323, inlinedAt: !324)
+!323 = distinct !DISubprogram(name: "count<std::env::Args>", linkageName: "_ZN4core4iter6traits8iterator8Iterator5count17hab149eb6308cf23fE", scope: !192, file: !191, line: 225, type: !12, scopeLine: 225, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!324 = !DILocation(line: 19, column: 30, scope: !320)
+!325 = !DILocation(line: 19, column: 36, scope: !320)
+!326 = !DILocation(line: 20, column: 41, scope: !327)
+!327 = distinct !DILexicalBlock(scope: !320, file: !297, line: 19, column: 5)
+!328 = !DILocation(line: 20, column: 24, scope: !327)
+!329 = !DILocation(line: 20, column: 5, scope: !327)
+!330 = distinct !DISubprogram(name: "helper", linkageName: "_ZN7explain6helper17h8098f1ddcc15b792E", scope: !298, file: !297, line: 4, type: !194, scopeLine: 4, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !6, templateParams: !13)
+!331 = !DILocation(line: 5, column: 5, scope: !330)
+!332 = !DILocation(line: 6, column: 2, scope: !330)
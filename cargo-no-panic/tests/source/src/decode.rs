@@ -0,0 +1,10 @@
+mod util;
+
+#[no_panic::no_panic]
+pub trait Peek {
+    fn peek(&self) -> Option<u8> {
+        None
+    }
+
+    fn required(&self) -> u8;
+}
@@ -0,0 +1,10 @@
+pub fn len(bytes: &[u8]) -> usize {
+    bytes.len()
+}
+
+#[no_panic::no_panic]
+mod checked {
+    pub fn len(bytes: &[u8]) -> usize {
+        bytes.len()
+    }
+}
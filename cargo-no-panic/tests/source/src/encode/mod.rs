@@ -0,0 +1,4 @@
+#[no_panic::no_panic(msg = "hot path")]
+pub fn encode(byte: u8) -> [u8; 2] {
+    [byte >> 4, byte & 0xf]
+}
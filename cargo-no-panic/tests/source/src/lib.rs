@@ -0,0 +1,59 @@
+use no_panic::no_panic;
+
+mod decode;
+mod encode;
+#[path = "misc/helpers.rs"]
+mod helpers;
+
+#[no_panic(verify)]
+pub fn first(bytes: &[u8]) -> Option<u8> {
+    bytes.first().copied()
+}
+
+pub fn unchecked(bytes: &[u8]) -> u8 {
+    bytes[0]
+}
+
+pub struct Buffer(Vec<u8>);
+
+#[no_panic]
+impl Buffer {
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[no_panic(skip)]
+    pub fn at(&self, i: usize) -> u8 {
+        self.0[i]
+    }
+}
+
+impl Clone for Buffer {
+    #[no_panic]
+    fn clone(&self) -> Self {
+        Buffer(self.0.clone())
+    }
+}
+
+pub fn generic<T: Copy>(value: T) -> T {
+    value
+}
+
+pub struct Reader(Vec<u8>);
+
+impl Reader {
+    pub fn get(&self, i: usize) -> Option<u8> {
+        self.0.get(i).copied()
+    }
+
+    pub async fn fill(&mut self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    fn fixture() -> Vec<u8> {
+        Vec::new()
+    }
+}
+
+#[inline] fn twice(x: u32) -> u32 { x.wrapping_mul(2) }
@@ -0,0 +1,4 @@
+mod inner {
+    #[no_panic::no_panic]
+    fn helper() {}
+}
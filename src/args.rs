@@ -0,0 +1,223 @@
+use crate::mode::{self, Mode};
+use proc_macro2::{Span, TokenStream};
+use syn::meta::ParseNestedMeta;
+use syn::parse::{Error, Parser, Result};
+use syn::punctuated::Punctuated;
+use syn::{
+    parenthesized, AngleBracketedGenericArguments, Attribute, GenericArgument, LitStr, Meta, Token,
+};
+
+#[derive(Clone, Default)]
+pub(crate) struct Args {
+    pub skip: bool,
+    // Guard each statement of the function body separately as well.
+    pub granular: bool,
+    // Guard each poll of a future awaited by an async fn as well.
+    pub poll: bool,
+    // On a trait, require that every impl of it is checked. On an impl of a
+    // trait, meet that requirement.
+    pub contract: bool,
+    // Replaces the crate-wide debug policy.
+    pub debug: Option<Mode>,
+    // Included in the linker error, for whoever has to deal with it.
+    pub msg: Option<String>,
+    pub id: Option<String>,
+    pub owner: Option<String>,
+    // Check the function in verification builds even if nothing calls it,
+    // through a copy of it.
+    pub verify: bool,
+    // Generic arguments to check a generic function with.
+    pub instantiate: Option<Instantiate>,
+}
+
+#[derive(Clone)]
+pub(crate) struct Instantiate {
+    pub span: Span,
+    // One list of generic arguments per instantiation.
+    pub args: Vec<Punctuated<GenericArgument, Token![,]>>,
+}
+
+impl Args {
+    fn parse_into(&mut self, args: TokenStream) -> Result<()> {
+        let mut msg = None;
+        let mut id = None;
+        let mut owner = None;
+        let mut debug = None;
+        let mut verify = false;
+        let mut instantiate = None;
+        syn::meta::parser(|meta| {
+            if meta.path.is_ident("skip") {
+                self.skip = true;
+                Ok(())
+            } else if meta.path.is_ident("granular") {
+                self.granular = true;
+                Ok(())
+            } else if meta.path.is_ident("poll") {
+                self.poll = true;
+                Ok(())
+            } else if meta.path.is_ident("contract") {
+                self.contract = true;
+                Ok(())
+            } else if meta.path.is_ident("debug") {
+                parse_mode(&meta, &mut debug)
+            } else if meta.path.is_ident("msg") {
+                parse_string(&meta, &mut msg)
+            } else if meta.path.is_ident("id") {
+                parse_string(&meta, &mut id)
+            } else if meta.path.is_ident("owner") {
+                parse_string(&meta, &mut owner)
+            } else if meta.path.is_ident("verify") {
+                verify = true;
+                Ok(())
+            } else if meta.path.is_ident("instantiate") {
+                parse_instantiate(&meta, &mut instantiate)
+            } else {
+                Err(meta.error("unsupported no_panic argument"))
+            }
+        })
+        .parse2(args)?;
+        // An argument on a nested item replaces the one from the enclosing item.
+        self.msg = msg.or(self.msg.take());
+        self.id = id.or(self.id.take());
+        self.owner = owner.or(self.owner.take());
+        self.debug = debug.or(self.debug.take());
+        // Generic arguments, and the copy to check, only make sense for the
+        // function they are written on.
+        self.verify = verify;
+        self.instantiate = instantiate;
+        Ok(())
+    }
+}
+
+fn parse_string(meta: &ParseNestedMeta, slot: &mut Option<String>) -> Result<()> {
+    let lit: LitStr = meta.value()?.parse()?;
+    if slot.is_some() {
+        return Err(meta.error("duplicate no_panic argument"));
+    }
+    *slot = Some(lit.value());
+    Ok(())
+}
+
+fn parse_mode(meta: &ParseNestedMeta, slot: &mut Option<Mode>) -> Result<()> {
+    let lit: LitStr = meta.value()?.parse()?;
+    if slot.is_some() {
+        return Err(meta.error("duplicate no_panic argument"));
+    }
+    match mode::from_name(&lit.value()) {
+        Some(mode) => *slot = Some(mode),
+        None => {
+            return Err(Error::new(
+                lit.span(),
+                "unsupported debug mode, expected \"enforce\", \"abort\" or \"off\"",
+            ));
+        }
+    }
+    Ok(())
+}
+
+// `instantiate(u8, u64)` for a function with one type parameter, or
+// `instantiate(<u8, 4>, <u64, 8>)` for a function with several.
+fn parse_instantiate(meta: &ParseNestedMeta, slot: &mut Option<Instantiate>) -> Result<()> {
+    let content;
+    parenthesized!(content in meta.input);
+    let mut args = Vec::new();
+    while !content.is_empty() {
+        if content.peek(Token![<]) {
+            let bracketed: AngleBracketedGenericArguments = content.parse()?;
+            args.push(bracketed.args);
+        } else {
+            let mut single = Punctuated::new();
+            single.push(content.parse()?);
+            args.push(single);
+        }
+        if content.is_empty() {
+            break;
+        }
+        content.parse::<Token![,]>()?;
+    }
+    if slot.is_some() {
+        return Err(meta.error("duplicate no_panic argument"));
+    }
+    *slot = Some(Instantiate {
+        span: meta.path.get_ident().unwrap().span(),
+        args,
+    });
+    Ok(())
+}
+
+// For arguments on anything other than a function.
+pub(crate) fn reject_instantiate(args: &Args) -> Result<()> {
+    match &args.instantiate {
+        Some(instantiate) => Err(Error::new(
+            instantiate.span,
+            "instantiate is only supported on a generic function",
+        )),
+        None => Ok(()),
+    }
+}
+
+pub(crate) fn reject_verify(args: &Args) -> Result<()> {
+    if args.verify {
+        Err(Error::new(
+            Span::call_site(),
+            "verify is only supported on a function",
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+// For arguments on anything other than a trait or an impl of a trait.
+pub(crate) fn reject_contract(args: &Args) -> Result<()> {
+    if args.contract {
+        Err(Error::new(
+            Span::call_site(),
+            "contract is only supported on a trait or an impl of a trait",
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+pub(crate) fn parse(args: TokenStream) -> Result<Args> {
+    let mut parsed = Args::default();
+    parsed.parse_into(args)?;
+    Ok(parsed)
+}
+
+// Remove any `#[no_panic]` from a function nested inside of an annotated item,
+// and combine its arguments with the ones of the enclosing attribute.
+pub(crate) fn take_inner(outer: &Args, attrs: &mut Vec<Attribute>) -> Result<Args> {
+    let mut args = outer.clone();
+    let mut result = Ok(());
+    attrs.retain(|attr| {
+        if !is_no_panic(attr) {
+            return true;
+        }
+        if let Meta::List(list) = &attr.meta {
+            if let Err(err) = args.parse_into(list.tokens.clone()) {
+                match &mut result {
+                    Ok(()) => result = Err(err),
+                    Err(errors) => errors.combine(err),
+                }
+            }
+        }
+        false
+    });
+    result.map(|()| args)
+}
+
+// Recognize `#[no_panic]` and `#[no_panic::no_panic]`.
+fn is_no_panic(attr: &Attribute) -> bool {
+    let path = attr.path();
+    let mut segments = path.segments.iter().map(|segment| &segment.ident);
+    match (segments.next(), segments.next(), segments.next()) {
+        (Some(first), None, None) => first == "no_panic",
+        (Some(first), Some(second), None) => first == "no_panic" && second == "no_panic",
+        _ => false,
+    }
+}
+
+pub(crate) fn strip_inner(attrs: &mut Vec<Attribute>) {
+    attrs.retain(|attr| !is_no_panic(attr));
+}
@@ -0,0 +1,147 @@
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use syn::visit_mut::{self, VisitMut};
+use syn::{parse_quote, Expr, Item, Lifetime};
+
+// Disarm the guard of an in-place guarded region around every point where
+// control leaves the region: `.await`, `?`, `return`, and any `break` or
+// `continue` whose target is outside of the region.
+pub(crate) struct DisarmGuard {
+    // Loops and labeled blocks entered within the region.
+    loops: usize,
+    labels: Vec<Lifetime>,
+    // The variable holding the guard, and an expression to rearm it with.
+    guard: Ident,
+    new_guard: TokenStream,
+    // Type of the guard to arm around each poll of an awaited future, through
+    // the `__NoPanicPoll` adapter.
+    poll_guard: Option<TokenStream>,
+}
+
+impl Default for DisarmGuard {
+    fn default() -> Self {
+        DisarmGuard::new(Ident::new("__guard", Span::call_site()), quote!(__NoPanic))
+    }
+}
+
+impl DisarmGuard {
+    pub fn new(guard: Ident, new_guard: TokenStream) -> Self {
+        DisarmGuard {
+            loops: 0,
+            labels: Vec::new(),
+            guard,
+            new_guard,
+            poll_guard: None,
+        }
+    }
+
+    pub fn guard_polls(mut self, guard_type: TokenStream) -> Self {
+        self.poll_guard = Some(guard_type);
+        self
+    }
+
+    fn leaves_region(&self, label: Option<&Lifetime>) -> bool {
+        match label {
+            Some(label) => !self.labels.contains(label),
+            None => self.loops == 0,
+        }
+    }
+}
+
+impl VisitMut for DisarmGuard {
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        let (is_loop, label) = match expr {
+            // These have their own `.await`, `?` and `return` scope.
+            Expr::Async(_) | Expr::Closure(_) | Expr::Const(_) => return,
+            Expr::Loop(expr) => (true, expr.label.as_ref()),
+            Expr::While(expr) => (true, expr.label.as_ref()),
+            Expr::ForLoop(expr) => (true, expr.label.as_ref()),
+            Expr::Block(expr) => (false, expr.label.as_ref()),
+            _ => (false, None),
+        };
+        let label = label.map(|label| label.name.clone());
+        let pushed_label = label.is_some();
+        self.loops += usize::from(is_loop);
+        self.labels.extend(label);
+        visit_mut::visit_expr_mut(self, expr);
+        self.loops -= usize::from(is_loop);
+        if pushed_label {
+            self.labels.pop();
+        }
+
+        let guard = &self.guard;
+        let new_guard = &self.new_guard;
+        match expr {
+            Expr::Await(await_expr) => {
+                let attrs = &await_expr.attrs;
+                let base = &await_expr.base;
+                let mut future = quote!(::core::future::IntoFuture::into_future(#base));
+                if let Some(poll_guard) = &self.poll_guard {
+                    future = quote! {
+                        __NoPanicPoll::<_, #poll_guard>(#future, ::core::marker::PhantomData)
+                    };
+                }
+                *expr = parse_quote!(#(#attrs)* {
+                    let __future = #future;
+                    ::core::mem::forget(#guard);
+                    let __output = __future.await;
+                    #guard = #new_guard;
+                    __output
+                });
+            }
+            Expr::Try(try_expr) => {
+                let attrs = &try_expr.attrs;
+                let inner = &try_expr.expr;
+                *expr = parse_quote!(#(#attrs)* {
+                    let __try = #inner;
+                    ::core::mem::forget(#guard);
+                    let __output = __try?;
+                    #guard = #new_guard;
+                    __output
+                });
+            }
+            Expr::Return(return_expr) => {
+                let attrs = &return_expr.attrs;
+                let value = if let Some(value) = &return_expr.expr {
+                    quote!(#value)
+                } else {
+                    quote!(())
+                };
+                *expr = parse_quote!(#(#attrs)* {
+                    let __return = #value;
+                    ::core::mem::forget(#guard);
+                    return __return;
+                });
+            }
+            Expr::Break(break_expr) if self.leaves_region(break_expr.label.as_ref()) => {
+                let attrs = &break_expr.attrs;
+                let label = &break_expr.label;
+                *expr = if let Some(value) = &break_expr.expr {
+                    parse_quote!(#(#attrs)* {
+                        let __break = #value;
+                        ::core::mem::forget(#guard);
+                        break #label __break;
+                    })
+                } else {
+                    parse_quote!(#(#attrs)* {
+                        ::core::mem::forget(#guard);
+                        break #label;
+                    })
+                };
+            }
+            Expr::Continue(continue_expr) if self.leaves_region(continue_expr.label.as_ref()) => {
+                let attrs = &continue_expr.attrs;
+                let label = &continue_expr.label;
+                *expr = parse_quote!(#(#attrs)* {
+                    ::core::mem::forget(#guard);
+                    continue #label;
+                });
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_item_mut(&mut self, _item: &mut Item) {
+        // Nested items are not part of the guarded region.
+    }
+}
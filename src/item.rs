@@ -0,0 +1,230 @@
+use crate::args::{self, Args};
+use crate::expand_no_panic;
+use crate::name::{path_name, type_name};
+use crate::retain::Context;
+use std::mem;
+use syn::parse::{Error, Result};
+use syn::{
+    parse_quote, Attribute, Block, FnModifiers, ImplItem, Item, ItemFn, ItemImpl, ItemMod,
+    ItemTrait, Signature, TraitItem, Visibility,
+};
+
+// Names passed to expand_no_panic from here are relative to the enclosing
+// module, which the linker error reports separately.
+
+pub(crate) fn expand_impl(args: &Args, imp: &mut ItemImpl) -> Result<()> {
+    if args.contract {
+        if imp.trait_.is_none() {
+            args::reject_contract(args)?;
+        }
+        fulfill(imp);
+    }
+    let context = Context::Impl {
+        self_ty: &imp.self_ty,
+        trait_: imp.trait_.as_ref().map(|(path, _for)| path),
+        generic: !imp.generics.params.is_empty() || imp.generics.where_clause.is_some(),
+    };
+    let self_name = type_name(&imp.self_ty);
+    let prefix = match &imp.trait_ {
+        Some((trait_path, _for)) => format!("<{} as {}>", self_name, path_name(trait_path)),
+        None => self_name,
+    };
+    for item in &mut imp.items {
+        let ImplItem::Fn(method) = item else {
+            continue;
+        };
+        let args = args::take_inner(args, &mut method.attrs)?;
+        if args.skip {
+            if args.contract {
+                return Err(Error::new(
+                    method.sig.ident.span(),
+                    "no_panic(skip) is not allowed in an impl that meets the contract of its trait",
+                ));
+            }
+            continue;
+        }
+        let name = format!("{}::{}", prefix, method.sig.ident);
+        expand_method(
+            &args,
+            &mut method.attrs,
+            &method.modifiers,
+            &mut method.sig,
+            &mut method.block,
+            &name,
+            &context,
+        )?;
+    }
+    Ok(())
+}
+
+pub(crate) fn expand_trait(args: &Args, trait_: &mut ItemTrait) -> Result<()> {
+    if args.contract {
+        require_contract(trait_);
+    }
+    let prefix = &trait_.ident;
+    for item in &mut trait_.items {
+        let TraitItem::Fn(method) = item else {
+            continue;
+        };
+        // A method without a body is left alone, so that a #[no_panic] on it
+        // still reports that it is not supported.
+        let Some(block) = &mut method.default else {
+            continue;
+        };
+        let args = args::take_inner(args, &mut method.attrs)?;
+        if args.skip {
+            continue;
+        }
+        let name = format!("{}::{}", prefix, method.sig.ident);
+        expand_method(
+            &args,
+            &mut method.attrs,
+            &method.modifiers,
+            &mut method.sig,
+            block,
+            &name,
+            &Context::Trait,
+        )?;
+    }
+    Ok(())
+}
+
+pub(crate) fn expand_mod(args: &Args, module: &mut ItemMod) -> Result<()> {
+    let Some((_brace, items)) = &mut module.content else {
+        return Ok(());
+    };
+    for item in items {
+        match item {
+            Item::Fn(function) => {
+                let args = args::take_inner(args, &mut function.attrs)?;
+                if !args.skip {
+                    let name = function.sig.ident.to_string();
+                    expand_no_panic(function, &name, &args, &Context::Mod)?;
+                }
+            }
+            Item::Impl(imp) => {
+                let args = args::take_inner(args, &mut imp.attrs)?;
+                args::reject_instantiate(&args)?;
+                args::reject_verify(&args)?;
+                if !args.skip {
+                    expand_impl(&args, imp)?;
+                }
+            }
+            Item::Trait(trait_) => {
+                let args = args::take_inner(args, &mut trait_.attrs)?;
+                args::reject_instantiate(&args)?;
+                args::reject_verify(&args)?;
+                if !args.skip {
+                    expand_trait(&args, trait_)?;
+                }
+            }
+            Item::Mod(module) => {
+                let args = args::take_inner(args, &mut module.attrs)?;
+                args::reject_instantiate(&args)?;
+                args::reject_verify(&args)?;
+                args::reject_contract(&args)?;
+                if !args.skip {
+                    expand_mod(&args, module)?;
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+// A trait annotated with `contract` requires the same of every impl of it,
+// through a hidden method that only such an impl provides. It is
+// left out of trait objects by the `Self: Sized` bound.
+pub(crate) fn require_contract(trait_: &mut ItemTrait) {
+    trait_.items.push(parse_quote! {
+        #[doc(hidden)]
+        fn __no_panic_contract()
+        where
+            Self: ::core::marker::Sized;
+    });
+}
+
+pub(crate) fn fulfill(imp: &mut ItemImpl) {
+    imp.items.push(parse_quote! {
+        #[doc(hidden)]
+        #[inline]
+        fn __no_panic_contract() {}
+    });
+}
+
+fn expand_method(
+    args: &Args,
+    attrs: &mut Vec<Attribute>,
+    modifiers: &FnModifiers,
+    sig: &mut Signature,
+    block: &mut Block,
+    name: &str,
+    context: &Context,
+) -> Result<()> {
+    let mut function = ItemFn {
+        attrs: mem::take(attrs),
+        vis: Visibility::Inherited,
+        modifiers: modifiers.clone(),
+        sig: sig.clone(),
+        block: Box::new(mem::replace(block, parse_quote!({}))),
+    };
+    expand_no_panic(&mut function, name, args, context)?;
+    *attrs = function.attrs;
+    *sig = function.sig;
+    *block = *function.block;
+    Ok(())
+}
+
+// The original item for doc builds, without the #[no_panic] attributes that
+// were consumed by the attribute on the enclosing item.
+
+pub(crate) fn strip_impl(imp: &mut ItemImpl) {
+    for item in &mut imp.items {
+        if let ImplItem::Fn(method) = item {
+            args::strip_inner(&mut method.attrs);
+        }
+    }
+}
+
+pub(crate) fn strip_trait(trait_: &mut ItemTrait) {
+    for item in &mut trait_.items {
+        if let TraitItem::Fn(method) = item {
+            if method.default.is_some() {
+                args::strip_inner(&mut method.attrs);
+            }
+        }
+    }
+}
+
+pub(crate) fn strip_mod(module: &mut ItemMod) {
+    let Some((_brace, items)) = &mut module.content else {
+        return;
+    };
+    for item in items {
+        match item {
+            Item::Fn(function) => args::strip_inner(&mut function.attrs),
+            Item::Impl(imp) => {
+                let args = args::take_inner(&Args::default(), &mut imp.attrs);
+                strip_impl(imp);
+                // Impls of the trait elsewhere may need the contract in doc
+                // builds, which only apply to the crate being documented.
+                if args.is_ok_and(|args| args.contract && !args.skip) {
+                    fulfill(imp);
+                }
+            }
+            Item::Trait(trait_) => {
+                let args = args::take_inner(&Args::default(), &mut trait_.attrs);
+                strip_trait(trait_);
+                if args.is_ok_and(|args| args.contract && !args.skip) {
+                    require_contract(trait_);
+                }
+            }
+            Item::Mod(module) => {
+                args::strip_inner(&mut module.attrs);
+                strip_mod(module);
+            }
+            _ => {}
+        }
+    }
+}
@@ -59,12 +59,143 @@
 //! 3002b8d9fE+0x2): undefined reference to `
 //!
 //!           ERROR[no-panic]: detected panic in function `demo`
+//!             module: no_panic_demo
+//!             location: src/main.rs:4:1
 //!           '
 //!           collect2: error: ld returned 1 exit status
 //! ```
 //!
 //! The error is not stellar but notice the ERROR\[no-panic\] part at the end
-//! that provides the name of the offending function.
+//! that provides the name and location of the offending function.
+//!
+//! For something more readable, install the companion Cargo subcommand with
+//! `cargo install cargo-no-panic` and build through it, as in `cargo no-panic
+//! build --release`. It turns each such linker error into a compiler-style
+//! error pointing at the offending function.
+//!
+//! The attribute can also be placed on an impl block or trait, to check every
+//! method in it that has a body, or on an inline module, to check every
+//! function, method and nested module in it. An individual item can opt out
+//! using `#[no_panic(skip)]`.
+//!
+//! ```
+//! # use no_panic::no_panic;
+//! #
+//! # struct Decoder {
+//! #     buffer: [u8; 16],
+//! # }
+//! #
+//! #[no_panic]
+//! impl Decoder {
+//!     fn peek(&self) -> Option<u8> {
+//!         self.buffer.first().copied()
+//!     }
+//!
+//!     #[no_panic(skip)]
+//!     fn byte_at(&self, i: usize) -> u8 {
+//!         self.buffer[i]
+//!     }
+//! }
+//! ```
+//!
+//! To check only part of a function, such as a hot inner loop, wrap that part
+//! in [`no_panic::block!`][block!] instead. A closure, such as a callback
+//! handed to other code, can be checked on every call by wrapping it in
+//! [`no_panic::closure!`][closure!].
+//!
+//! The `msg`, `id` and `owner` arguments are copied into the linker error, to
+//! tell whoever runs into it why the function must stay panic-free and who to
+//! ask about it.
+//!
+//! ```
+//! # use no_panic::no_panic;
+//! #
+//! #[no_panic(msg = "runs with the device lock held", id = "NET-1234", owner = "net-team")]
+//! fn demo(s: &str) -> &str {
+//!     s.trim()
+//! }
+//! #
+//! # fn main() {}
+//! ```
+//!
+//! ```console
+//!           ERROR[no-panic]: detected panic in function `demo`
+//!             module: no_panic_demo
+//!             location: src/main.rs:4:1
+//!             msg: runs with the device lock held
+//!             id: NET-1234
+//!             owner: net-team
+//! ```
+//!
+//! Every no-panic error has this shape, so that tools can pick it out of the
+//! linker output. The first line says what was checked: ``function `name` ``,
+//! ``statement in function `name` ``, `block` or `closure`. It is followed by
+//! one `key: value` field per line. `module` and `location` are always
+//! present, and `msg`, `id` and `owner` when given. A generic function also
+//! has `generics`, listing its type and const parameters, and `Self` when that
+//! is generic too. A function name does not repeat the module path; a method
+//! is named `Type::method`, or `<Type as Trait>::method`, when the attribute
+//! is on its impl block, and by just its own name otherwise, since the
+//! attribute on a method cannot see the impl it is in. `cargo no-panic` names
+//! such a method by its type too, as found at its location in the source or in
+//! the name of the function that the linker reports as referring to the error.
+//! Tools should skip fields they do not recognize, since more may be added.
+//!
+//! The linker error of a generic function comes from a guard type generic
+//! over the same parameters, whose drop glue the linker names as the place
+//! referring to the error, such as `<lib::nth::__NoPanic<u8> as Drop>::drop`
+//! in an object file of the crate that instantiated `nth` with `u8`. The
+//! generic arguments only appear there with
+//! `RUSTFLAGS="-C symbol-mangling-version=v0"`.
+//!
+//! A generic function is only checked for the generic arguments some caller
+//! uses it with. The `instantiate` argument lists generic arguments that it
+//! should also be checked with in a verification build (see the caveats
+//! below), whether or not anything uses it that way. Each instantiation then
+//! gets its own error naming the arguments, such as ``function `nth::<u8>` ``.
+//! A function with several type or const parameters takes one `<...>` list
+//! per instantiation, as in `instantiate(<u8, 4>, <u64, 8>)`.
+//!
+//! ```
+//! # use no_panic::no_panic;
+//! #
+//! #[no_panic(instantiate(u8, String))]
+//! fn last<T: Clone>(slice: &[T]) -> Option<T> {
+//!     slice.last().cloned()
+//! }
+//! #
+//! # fn main() {}
+//! ```
+//!
+//! To find out which part of a long function can panic, `granular` gives each
+//! statement of the function body a check of its own. Every statement that can
+//! still panic then gets its own error, ``statement in function `name` ``,
+//! whose `location` is that of the statement. A panic outside of any statement,
+//! such as in the drop of an argument, still reports the function as a whole.
+//!
+//! ```
+//! # use no_panic::no_panic;
+//! #
+//! #[no_panic(granular)]
+//! fn parse_header(bytes: &[u8]) -> Option<(u8, u8)> {
+//!     let version = *bytes.first()?;
+//!     let flags = *bytes.get(1)?;
+//!     Some((version, flags))
+//! }
+//! #
+//! # fn main() {}
+//! ```
+//!
+//! A panic in checked code is a link error by default. Compiling a crate with
+//! `--cfg no_panic="abort"`, for example through RUSTFLAGS in a fuzzing build,
+//! instead makes such a panic abort the process at runtime after printing the
+//! same message, like [noexcept] does. With `--cfg no_panic="off"` nothing is
+//! checked, which can be handy for quick debug builds. The mode used when there
+//! is no such cfg can be changed from `enforce` to `abort` or `off` by setting
+//! the environment variable `NO_PANIC` while building; Cargo rebuilds no-panic
+//! and everything using it when that variable changes.
+//!
+//! [noexcept]: https://docs.rs/noexcept
 //!
 //! <br>
 //!
@@ -77,15 +208,57 @@
 //! - Panic detection happens at link time across the entire dependency graph,
 //!   so any Cargo commands that do not invoke a linker will not trigger panic
 //!   detection. This includes `cargo build` of library crates and `cargo check`
-//!   of binary and library crates.
+//!   of binary and library crates. `cargo no-panic verify` additionally checks
+//!   the object code of the rlib, staticlib and cdylib crates in a workspace,
+//!   although only within each crate, without the optimization that linking
+//!   with LTO would bring.
+//!
+//! - A function is normally only checked as it ends up compiled into its
+//!   callers, so one that nothing calls is not checked at all. In a
+//!   verification build, annotated impl blocks and modules also have their
+//!   functions compiled on their own and checked that way, whether or not
+//!   anything calls them. `cargo no-panic verify` is one; otherwise compile
+//!   with `--cfg no_panic_verify` or set the environment variable
+//!   `NO_PANIC_VERIFY=1` while building. A function annotated on its own is
+//!   only checked that way with `#[no_panic(verify)]`, which compiles a nested
+//!   copy of it, so it must not use `self`, `Self` or generic parameters of an
+//!   enclosing impl; otherwise annotate the impl block as a whole instead. This
+//!   is not done for methods of generic impls, trait default methods, async
+//!   functions, functions with `impl Trait` parameters, and generic functions
+//!   other than with `instantiate`. `cargo no-panic verify` warns about the
+//!   annotated functions that it does not check on their own.
 //!
-//! - The attribute is useless in code built with `panic = "abort"`. Code must
-//!   be built with `panic = "unwind"` (the default) in order for any panics to
-//!   be detected. After confirming absence of panics, you can of course still
-//!   ship your software as a `panic = "abort"` build.
+//! - Panics can only be detected in code built with `panic = "unwind"` (the
+//!   default), so in a `panic = "abort"` build the attribute checks nothing
+//!   and warns about it. After confirming absence of panics, you can of course
+//!   still ship your software as a `panic = "abort"` build, and build it with
+//!   `--cfg no_panic="off"` or `NO_PANIC=off` to silence the warning.
 //!
-//! - Const functions are not supported. The attribute will fail to compile if
-//!   placed on a `const fn`.
+//! - On a trait method, the attribute checks the default body provided by the
+//!   trait. It cannot be placed on a trait method without a body. A trait
+//!   annotated with `#[no_panic(contract)]` requires every impl of it to be
+//!   checked too: an impl only compiles if it is written as
+//!   `#[no_panic(contract)] impl Trait for T`, which checks all of its methods
+//!   and may not skip any. Adding `contract` to a public trait is a breaking
+//!   change for the impls of it in other crates.
+//!
+//! - On a `const fn`, only calls at runtime are checked. A panic during const
+//!   evaluation is already a compile error.
+//!
+//! - On an `async fn`, the check covers the code of the function body between
+//!   suspension points. Futures awaited by the body are polled outside of the
+//!   check, so they need to be proven separately, for example by marking the
+//!   async functions they come from `#[no_panic]` too. `#[no_panic(poll)]`
+//!   checks each poll of an awaited future as well. That suits futures
+//!   implemented by hand: the poll of a future from another `async fn` or
+//!   `async` block always has a path to a panic, for being resumed after it
+//!   completed, which the optimizer cannot rule out, so awaiting one with
+//!   `poll` fails the check.
+//!
+//! - In an `async fn`, a `const fn`, a `no_panic::block!` or a
+//!   `no_panic::closure!`, a `.await`, `?`, `return`, `break` or `continue`
+//!   inside of a macro invocation cannot be seen by no-panic, and will cause a
+//!   spurious failure.
 //!
 //! If you find that code requires optimization to pass `#[no_panic]`, either
 //! make no-panic an optional dependency that you only enable in release builds,
@@ -97,6 +270,15 @@
 //! opt-level = 1
 //! ```
 //!
+//! Alternatively, choose what happens in builds with debug assertions, which
+//! are usually unoptimized. `#[no_panic(debug = "off")]` checks nothing there,
+//! and `#[no_panic(debug = "abort")]` aborts at runtime on a panic, while
+//! release builds keep enforcing. For the whole crate, compile it with
+//! `--cfg no_panic_debug="off"` or `"abort"`, or set the environment variable
+//! `NO_PANIC_DEBUG` while building. The argument on a function takes precedence
+//! over the crate-wide setting, and `debug = "enforce"` restores the link
+//! error.
+//!
 //! If the code that you need to prove isn't panicking makes function calls to
 //! non-generic non-inline functions from a different crate, you may need thin
 //! LTO enabled for the linker to deduce those do not panic.
@@ -137,35 +319,52 @@
     clippy::doc_markdown,
     clippy::match_same_arms,
     clippy::missing_panics_doc,
+    clippy::struct_excessive_bools,
+    clippy::too_many_lines,
     clippy::uninlined_format_args
 )]
 #![cfg_attr(all(test, exhaustive), feature(non_exhaustive_omitted_patterns_lint))]
 
 extern crate proc_macro;
 
+mod args;
+mod disarm;
+mod item;
+mod mode;
+mod name;
+mod retain;
+
+use crate::args::Args;
+use crate::disarm::DisarmGuard;
+use crate::mode::Mode;
+use crate::retain::Context;
 use proc_macro::TokenStream;
 use proc_macro2::{Span, TokenStream as TokenStream2};
-use quote::quote;
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use std::fmt::Write as _;
 use std::mem;
-use syn::parse::{Error, Nothing, Result};
+use syn::parse::Parser;
+use syn::parse::{Error, Result};
+use syn::spanned::Spanned;
+use syn::visit_mut::VisitMut;
 use syn::{
-    parse_quote, FnArg, GenericArgument, Ident, ItemFn, Pat, PatType, Path, PathArguments,
-    ReturnType, Token, Type, TypeInfer, TypeParamBound,
+    parse_quote, Block, ExprClosure, FnArg, GenericArgument, GenericParam, Ident, Item, ItemFn,
+    ItemImpl, ItemMod, ItemTrait, Pat, PatType, Path, PathArguments, ReturnType, Signature, Stmt,
+    StmtMacro, Token, TraitItemFn, Type, TypeInfer, TypeParamBound,
 };
 
 #[proc_macro_attribute]
 pub fn no_panic(args: TokenStream, input: TokenStream) -> TokenStream {
     let args = TokenStream2::from(args);
     let input = TokenStream2::from(input);
-    TokenStream::from(match parse(args, input.clone()) {
-        Ok(function) => {
-            let expanded = expand_no_panic(function);
+    TokenStream::from(match parse(args, input.clone()).and_then(expand) {
+        Ok((expanded, original)) => {
             quote! {
                 #[cfg(not(doc))]
                 #expanded
                 // Keep generated parameter names out of doc builds.
                 #[cfg(doc)]
-                #input
+                #original
             }
         }
         Err(parse_error) => {
@@ -178,22 +377,177 @@ pub fn no_panic(args: TokenStream, input: TokenStream) -> TokenStream {
     })
 }
 
-fn parse(args: TokenStream2, input: TokenStream2) -> Result<ItemFn> {
-    let function: ItemFn = syn::parse2(input)?;
-    let _: Nothing = syn::parse2::<Nothing>(args)?;
-    if function.sig.constness.is_some() {
-        return Err(Error::new(
-            Span::call_site(),
-            "no_panic attribute on const fn is not supported",
-        ));
+/// Require that the compiler prove a block inside of a function can't ever
+/// panic.
+///
+/// ```
+/// # #[inline]
+/// fn checksum(bytes: &[u8]) -> u8 {
+///     let mut sum = 0u8;
+///     for byte in bytes {
+///         sum = no_panic::block! {
+///             sum.wrapping_add(*byte)
+///         };
+///     }
+///     sum
+/// }
+/// #
+/// # fn main() {}
+/// ```
+///
+/// The block may use `?`, `return`, `break` and `continue` to leave the
+/// enclosing function or loop, and those exits are not treated as a panic.
+/// Panic detection then works the same way as for `#[no_panic]`, with a linker
+/// error that identifies the block by its module and source location. The
+/// error does not name the function that the block is in. `cargo no-panic`
+/// adds it from the function that the linker reports as referring to the
+/// block, which is a different function if the one with the block was inlined
+/// into it.
+#[proc_macro]
+pub fn block(input: TokenStream) -> TokenStream {
+    let input = TokenStream2::from(input);
+    let parsed = mode::check().and_then(|()| Block::parse_within.parse2(input.clone()));
+    TokenStream::from(match parsed {
+        Ok(stmts) => expand_block(stmts),
+        Err(parse_error) => {
+            let compile_error = parse_error.to_compile_error();
+            quote! {
+                {
+                    #compile_error
+                    #input
+                }
+            }
+        }
+    })
+}
+
+/// Require that the compiler prove a closure can't ever panic when called.
+///
+/// ```
+/// # #[inline]
+/// fn sum_of_lengths(words: &[&str]) -> usize {
+///     let len = no_panic::closure!(|word: &&str| word.len());
+///     words.iter().map(len).fold(0, usize::wrapping_add)
+/// }
+/// #
+/// # fn main() {}
+/// ```
+///
+/// Every call of the closure is checked. Panic detection works the same way as
+/// for `#[no_panic]`, with a linker error that identifies the closure by its
+/// module and source location. As for [`block!`], `cargo no-panic` adds the
+/// function that it is in as far as the linker output tells.
+#[proc_macro]
+pub fn closure(input: TokenStream) -> TokenStream {
+    let input = TokenStream2::from(input);
+    let parsed = mode::check().and_then(|()| syn::parse2(input.clone()));
+    TokenStream::from(match parsed.and_then(expand_closure) {
+        Ok(expanded) => expanded,
+        Err(parse_error) => {
+            let compile_error = parse_error.to_compile_error();
+            quote! {
+                {
+                    #compile_error
+                    #input
+                }
+            }
+        }
+    })
+}
+
+enum Input {
+    Fn(ItemFn),
+    Impl(ItemImpl),
+    Trait(ItemTrait),
+    Mod(ItemMod),
+}
+
+fn parse(args: TokenStream2, input: TokenStream2) -> Result<(Args, Input)> {
+    mode::check()?;
+    let input = match syn::parse2(input.clone())? {
+        Item::Fn(function) => Input::Fn(function),
+        Item::Impl(imp) => Input::Impl(imp),
+        Item::Trait(trait_) => Input::Trait(trait_),
+        Item::Mod(module) if module.content.is_some() => Input::Mod(module),
+        Item::Mod(_) => {
+            return Err(Error::new(
+                Span::call_site(),
+                "no_panic attribute on a non-inline module is not supported",
+            ));
+        }
+        _ => {
+            return Err(match syn::parse2(input) {
+                Ok(TraitItemFn { default: None, .. }) => Error::new(
+                    Span::call_site(),
+                    "no_panic attribute on a trait method without a body is not supported; put #[no_panic(contract)] on the trait to require it of every impl",
+                ),
+                _ => Error::new(
+                    Span::call_site(),
+                    "no_panic attribute is only supported on functions, impl blocks, traits and inline modules",
+                ),
+            });
+        }
+    };
+    let args = args::parse(args)?;
+    Ok((args, input))
+}
+
+// Produce the checked item, alongside the original item for doc builds.
+fn expand((args, input): (Args, Input)) -> Result<(TokenStream2, TokenStream2)> {
+    let original = match &input {
+        Input::Fn(function) => function.to_token_stream(),
+        Input::Impl(imp) => {
+            let mut original = imp.clone();
+            item::strip_impl(&mut original);
+            if args.contract && !args.skip && imp.trait_.is_some() {
+                item::fulfill(&mut original);
+            }
+            original.into_token_stream()
+        }
+        Input::Trait(trait_) => {
+            let mut original = trait_.clone();
+            item::strip_trait(&mut original);
+            if args.contract && !args.skip {
+                item::require_contract(&mut original);
+            }
+            original.into_token_stream()
+        }
+        Input::Mod(module) => {
+            let mut original = module.clone();
+            item::strip_mod(&mut original);
+            original.into_token_stream()
+        }
+    };
+    if args.skip {
+        return Ok((original.clone(), original));
     }
-    if function.sig.asyncness.is_some() {
-        return Err(Error::new(
-            Span::call_site(),
-            "no_panic attribute on async fn is not supported",
-        ));
+    if !matches!(input, Input::Fn(_)) {
+        args::reject_instantiate(&args)?;
+        args::reject_verify(&args)?;
     }
-    Ok(function)
+    if !matches!(input, Input::Impl(_) | Input::Trait(_)) {
+        args::reject_contract(&args)?;
+    }
+    let expanded = match input {
+        Input::Fn(mut function) => {
+            let name = function.sig.ident.to_string();
+            expand_no_panic(&mut function, &name, &args, &Context::Fn)?;
+            function.into_token_stream()
+        }
+        Input::Impl(mut imp) => {
+            item::expand_impl(&args, &mut imp)?;
+            imp.into_token_stream()
+        }
+        Input::Trait(mut trait_) => {
+            item::expand_trait(&args, &mut trait_)?;
+            trait_.into_token_stream()
+        }
+        Input::Mod(mut module) => {
+            item::expand_mod(&args, &mut module)?;
+            module.into_token_stream()
+        }
+    };
+    Ok((expanded, original))
 }
 
 // Convert `Path<impl Trait>` to `Path<_>`
@@ -238,7 +592,19 @@ fn make_impl_trait_wild_in_path(path: &mut Path) {
     }
 }
 
-fn expand_no_panic(mut function: ItemFn) -> TokenStream2 {
+fn expand_no_panic(
+    function: &mut ItemFn,
+    name: &str,
+    args: &Args,
+    context: &Context,
+) -> Result<()> {
+    let retain = retain::prepare(function, context, args)?;
+    let self_generic = matches!(
+        context,
+        Context::Trait | Context::Impl { generic: true, .. }
+    );
+    let generics = GuardGenerics::new(&function.sig, self_generic);
+    let new_guard = generics.new_guard();
     let mut move_self = None;
     let mut arg_attrs = Vec::new();
     let mut arg_pat = Vec::new();
@@ -283,48 +649,555 @@ fn expand_no_panic(mut function: ItemFn) -> TokenStream2 {
     }
 
     let ret = match &function.sig.output {
-        ReturnType::Default => quote!(-> ()),
-        ReturnType::Type(arrow, output) => {
+        ReturnType::Default => quote!(()),
+        ReturnType::Type(_, output) => {
             let mut output = output.clone();
             make_impl_trait_wild(&mut output);
-            quote!(#arrow #output)
+            quote!(#output)
         }
     };
-    let stmts = function.block.stmts;
-    let message = format!(
-        "\n\nERROR[no-panic]: detected panic in function `{}`\n",
-        function.sig.ident,
+    let subject = format!("function `{}`", name);
+    // `::<u8>` of a copy made for `instantiate`, which sets its key apart
+    // from those of the function's other instantiations.
+    let instantiation = name.find("::<").map_or("", |i| &name[i..]);
+    if args.granular {
+        let stmts = mem::take(&mut function.block.stmts);
+        function.block.stmts = guard_statements(stmts, &subject, instantiation, &ret, args);
+    }
+    let stmts = &mut function.block.stmts;
+    let span = function.sig.fn_token.span;
+    let message = message(&subject, &generics.names, span, args);
+    let key = key(span, instantiation);
+    let body = if function.sig.asyncness.is_some() || function.sig.constness.is_some() {
+        // A const fn cannot call a closure, and an async fn's own poll cannot
+        // be guarded since every async state machine contains a panic path for
+        // being resumed after completion. Instead the body is guarded in
+        // place, and the guard is disarmed while the function is suspended or
+        // returning, so that dropping or completing the future never reaches
+        // `trigger`. During const evaluation the guard is inert; a panic there
+        // is already a compile error. With `poll`, each poll of an awaited
+        // future is guarded by an adapter; awaiting another async state
+        // machine then fails for the same reason, so this is opt-in.
+        let guard = Ident::new("__guard", Span::call_site());
+        let mut disarm = DisarmGuard::new(guard, new_guard.clone());
+        let poll = if args.poll && function.sig.asyncness.is_some() {
+            disarm = disarm.guard_polls(generics.guard_type());
+            Some(poll_adapter(&generics))
+        } else {
+            None
+        };
+        for stmt in stmts.iter_mut() {
+            disarm.visit_stmt_mut(stmt);
+        }
+        quote! {
+            #poll
+            #[allow(unused_mut)]
+            let mut __guard = #new_guard;
+            let __result: #ret = {
+                #(
+                    #(#arg_attrs)*
+                    let #arg_pat = #arg_val;
+                )*
+                #(#stmts)*
+            };
+            #[allow(unreachable_code)]
+            ::core::mem::forget(__guard);
+            __result
+        }
+    } else {
+        quote! {
+            let __guard = #new_guard;
+            let __result = (move || -> #ret {
+                #move_self
+                #(
+                    #(#arg_attrs)*
+                    let #arg_pat = #arg_val;
+                )*
+                #(#stmts)*
+            })();
+            ::core::mem::forget(__guard);
+            __result
+        }
+    };
+    let guard_type = Ident::new("__NoPanic", Span::call_site());
+    let guard = guard(&guard_type, &message, &key, args.debug, &generics);
+    *function.block = parse_quote!({
+        #guard
+        #body
+    });
+
+    // In a verification build, check the function even if nothing calls it.
+    // Other builds are left alone, because the function on its own may have
+    // panic paths that every one of its callers optimizes away.
+    if let Some(retain) = retain {
+        let enforce = mode::enforce_cfg(args.debug);
+        let verify = mode::verify_cfg();
+        let cfg = quote!(all(#enforce, #verify));
+        retain::apply(retain, function, name, args, &cfg)?;
+    }
+    Ok(())
+}
+
+// Give each statement of a function body a guard of its own, whose linker
+// error has the location of the statement. Like the guard of an async fn, it
+// is disarmed wherever control leaves the statement without panicking.
+fn guard_statements(
+    stmts: Vec<Stmt>,
+    subject: &str,
+    instantiation: &str,
+    ret: &TokenStream2,
+    args: &Args,
+) -> Vec<Stmt> {
+    let subject = format!("statement in {}", subject);
+    let var = Ident::new("__stmt_guard", Span::call_site());
+    let len = stmts.len();
+    let mut guarded = Vec::new();
+    for (i, mut stmt) in stmts.into_iter().enumerate() {
+        if let Stmt::Item(_) = stmt {
+            guarded.push(stmt);
+            continue;
+        }
+        let guard_type = format_ident!("__NoPanicStmt{}", i);
+        let message = message(&subject, &[], stmt.span(), args);
+        let key = key(stmt.span(), instantiation);
+        let guard = guard(
+            &guard_type,
+            &message,
+            &key,
+            args.debug,
+            &GuardGenerics::default(),
+        );
+        DisarmGuard::new(var.clone(), quote!(#guard_type)).visit_stmt_mut(&mut stmt);
+        // The value of the body is held onto while the guard is disarmed.
+        let is_tail = i + 1 == len
+            && matches!(
+                stmt,
+                Stmt::Expr(_, None)
+                    | Stmt::Macro(StmtMacro {
+                        semi_token: None,
+                        ..
+                    }),
+            );
+        let block: Block = if is_tail {
+            parse_quote!({
+                #guard
+                #[allow(unused_mut)]
+                let mut #var = #guard_type;
+                let __value: #ret = #stmt;
+                #[allow(unreachable_code)]
+                ::core::mem::forget(#var);
+                __value
+            })
+        } else {
+            parse_quote!({
+                #guard
+                #[allow(unused_mut)]
+                let mut #var = #guard_type;
+                #stmt
+                #[allow(unreachable_code)]
+                ::core::mem::forget(#var);
+            })
+        };
+        guarded.extend(block.stmts);
+    }
+    guarded
+}
+
+fn expand_block(mut stmts: Vec<Stmt>) -> TokenStream2 {
+    let mut disarm = DisarmGuard::default();
+    for stmt in &mut stmts {
+        disarm.visit_stmt_mut(stmt);
+    }
+    let message = message("block", &[], Span::call_site(), &Args::default());
+    let key = key(Span::call_site(), "");
+    let guard_type = Ident::new("__NoPanic", Span::call_site());
+    let guard = guard(&guard_type, &message, &key, None, &GuardGenerics::default());
+    quote! {
+        {
+            #guard
+            #[allow(unused_mut)]
+            let mut __guard = __NoPanic;
+            let __result = {
+                #(#stmts)*
+            };
+            #[allow(unreachable_code)]
+            ::core::mem::forget(__guard);
+            __result
+        }
+    }
+}
+
+fn expand_closure(mut closure: ExprClosure) -> Result<TokenStream2> {
+    if let Some(async_token) = &closure.asyncness {
+        return Err(Error::new_spanned(
+            async_token,
+            "no_panic::closure! on async closure is not supported",
+        ));
+    }
+    let body = &mut closure.body;
+    DisarmGuard::default().visit_expr_mut(body);
+    let ret = match &closure.output {
+        ReturnType::Default => None,
+        ReturnType::Type(_, ret) => Some(quote!(: #ret)),
+    };
+    *body = parse_quote!({
+        #[allow(unused_mut)]
+        let mut __guard = __NoPanic;
+        let __result #ret = #body;
+        #[allow(unreachable_code)]
+        ::core::mem::forget(__guard);
+        __result
+    });
+    let message = message("closure", &[], Span::call_site(), &Args::default());
+    let key = key(Span::call_site(), "");
+    let guard_type = Ident::new("__NoPanic", Span::call_site());
+    let guard = guard(&guard_type, &message, &key, None, &GuardGenerics::default());
+    Ok(quote! {
+        {
+            #guard
+            #closure
+        }
+    })
+}
+
+// Generic parameters of a function's guard: `Self` where it is generic, and
+// the type and const parameters of the function. Each instantiation of the
+// function then drops its own instantiation of the guard.
+#[derive(Default)]
+struct GuardGenerics {
+    // Parameters of `__NoPanic`, the same ones as arguments, and those of them
+    // that are types.
+    params: Vec<TokenStream2>,
+    idents: Vec<Ident>,
+    types: Vec<Ident>,
+    // What the function calls them, for the linker error.
+    names: Vec<String>,
+}
+
+impl GuardGenerics {
+    fn new(sig: &Signature, self_generic: bool) -> Self {
+        let mut generics = GuardGenerics::default();
+        if self_generic {
+            let ident = Ident::new("__Self", Span::call_site());
+            generics.params.push(quote!(#ident: ?::core::marker::Sized));
+            generics.types.push(ident.clone());
+            generics.idents.push(ident);
+            generics.names.push("Self".to_owned());
+        }
+        for param in &sig.generics.params {
+            match param {
+                GenericParam::Type(param) => {
+                    let ident = &param.ident;
+                    generics.params.push(quote!(#ident: ?::core::marker::Sized));
+                    generics.types.push(ident.clone());
+                    generics.idents.push(ident.clone());
+                    generics.names.push(ident.to_string());
+                }
+                GenericParam::Const(param) => {
+                    let ident = &param.ident;
+                    let ty = &param.ty;
+                    generics.params.push(quote!(const #ident: #ty));
+                    generics.idents.push(ident.clone());
+                    generics.names.push(ident.to_string());
+                }
+                GenericParam::Lifetime(_) => {}
+            }
+        }
+        generics
+    }
+
+    // Expression creating the guard.
+    fn new_guard(&self) -> TokenStream2 {
+        if self.names.is_empty() {
+            return quote!(__NoPanic);
+        }
+        let args = self.args();
+        quote!(__NoPanic::<#(#args),*>(::core::marker::PhantomData))
+    }
+
+    // The type of the guard, as named in the function body.
+    fn guard_type(&self) -> TokenStream2 {
+        if self.names.is_empty() {
+            return quote!(__NoPanic);
+        }
+        let args = self.args();
+        quote!(__NoPanic<#(#args),*>)
+    }
+
+    fn args(&self) -> impl Iterator<Item = TokenStream2> + '_ {
+        self.names.iter().zip(&self.idents).map(|(name, ident)| {
+            if name == "Self" {
+                quote!(Self)
+            } else {
+                quote!(#ident)
+            }
+        })
+    }
+}
+
+// A future that arms a guard of type `G` around each poll of the future it
+// wraps, so that a panic in the poll of an awaited future is reported as one
+// in the async fn awaiting it.
+fn poll_adapter(generics: &GuardGenerics) -> TokenStream2 {
+    let GuardGenerics { params, idents, .. } = generics;
+    let new_guard = if generics.names.is_empty() {
+        quote!(__NoPanic)
+    } else {
+        quote!(__NoPanic(::core::marker::PhantomData))
+    };
+    quote! {
+        struct __NoPanicPoll<F, G>(F, ::core::marker::PhantomData<fn() -> G>);
+        impl<F, G> ::core::future::Future for __NoPanicPoll<F, G>
+        where
+            F: ::core::future::Future,
+            G: ::core::default::Default,
+        {
+            type Output = F::Output;
+            #[inline]
+            fn poll(
+                self: ::core::pin::Pin<&mut Self>,
+                cx: &mut ::core::task::Context,
+            ) -> ::core::task::Poll<F::Output> {
+                let guard = G::default();
+                // The future is never moved out of the adapter.
+                let future = unsafe { self.map_unchecked_mut(|adapter| &mut adapter.0) };
+                let poll = ::core::future::Future::poll(future, cx);
+                ::core::mem::forget(guard);
+                poll
+            }
+        }
+        impl<#(#params),*> ::core::default::Default for __NoPanic<#(#idents),*> {
+            #[inline]
+            fn default() -> Self {
+                #new_guard
+            }
+        }
+    }
+}
+
+// The name of the undefined symbol, in the format documented in the crate
+// docs. Tools parse this, so fields may be added but the existing ones must
+// keep their meaning.
+//
+// The location macros are spanned at `span`, which makes them report the
+// position of the annotated item or macro invocation rather than the position
+// of the attribute.
+fn message(subject: &str, generics: &[String], span: Span, args: &Args) -> TokenStream2 {
+    let header = format!(
+        "\n\nERROR[no-panic]: detected panic in {}\n  module: ",
+        subject,
     );
+    let mut fields = String::new();
+    if !generics.is_empty() {
+        let _ = writeln!(fields, "  generics: {}", generics.join(", "));
+    }
+    for (key, value) in [("msg", &args.msg), ("id", &args.id), ("owner", &args.owner)] {
+        if let Some(value) = value {
+            // One line per field.
+            let value = value.replace(['\r', '\n'], " ");
+            let _ = writeln!(fields, "  {}: {}", key, value);
+        }
+    }
+    let file = quote_spanned!(span=> ::core::file!());
+    let line = quote_spanned!(span=> ::core::line!());
+    let column = quote_spanned!(span=> ::core::column!());
+    quote! {
+        ::core::concat!(
+            #header,
+            ::core::module_path!(),
+            "\n  location: ",
+            #file,
+            ":",
+            #line,
+            ":",
+            #column,
+            "\n",
+            #fields,
+        )
+    }
+}
+
+// What identifies the guard in NO_PANIC_BASELINE, which cargo no-panic sets
+// to the failures listed in its baseline, one per line: the module, then the
+// location of the message, then for a copy made for `instantiate` its generic
+// arguments, as in `demo::decode src/decode.rs:27:5::<u8>`.
+fn key(span: Span, instantiation: &str) -> TokenStream2 {
+    let file = quote_spanned!(span=> ::core::file!());
+    let line = quote_spanned!(span=> ::core::line!());
+    let column = quote_spanned!(span=> ::core::column!());
+    quote! {
+        ::core::concat!(
+            ::core::module_path!(),
+            " ",
+            #file,
+            ":",
+            #line,
+            ":",
+            #column,
+            #instantiation,
+        )
+    }
+}
+
+// Whether NO_PANIC_BASELINE has a line equal to `key`, as a constant
+// expression. It is read by the crate being compiled rather than by no-panic,
+// so that Cargo rebuilds only that crate when it changes.
+fn listed(key: &dyn ToTokens) -> TokenStream2 {
+    quote! {
+        {
+            const fn listed(list: &[u8], key: &[u8]) -> bool {
+                let mut start = 0;
+                while start < list.len() {
+                    let mut end = start;
+                    while end < list.len() && list[end] != b'\n' {
+                        end += 1;
+                    }
+                    if end - start == key.len() {
+                        let mut i = 0;
+                        while i < key.len() && list[start + i] == key[i] {
+                            i += 1;
+                        }
+                        if i == key.len() {
+                            return true;
+                        }
+                    }
+                    start = end + 1;
+                }
+                false
+            }
+            match ::core::option_env!("NO_PANIC_BASELINE") {
+                ::core::option::Option::Some(list) => listed(list.as_bytes(), #key.as_bytes()),
+                ::core::option::Option::None => false,
+            }
+        }
+    }
+}
+
+// The `__NoPanic` type whose drop during unwinding references an undefined
+// symbol named by `message`, or in abort mode panics again with `message`,
+// which aborts. In off mode the drop does nothing. A guard whose `key` is
+// listed in NO_PANIC_BASELINE aborts even in enforce mode.
+//
+// The mode is selected by cfgs inside of `drop` rather than on the generated
+// items, because only there can the unexpected_cfgs lint be allowed; the
+// crate being compiled does not declare `no_panic` as a cfg.
+fn guard(
+    name: &Ident,
+    message: &dyn ToTokens,
+    key: &dyn ToTokens,
+    debug: Option<Mode>,
+    generics: &GuardGenerics,
+) -> TokenStream2 {
     let unsafe_extern = if cfg!(no_unsafe_extern_blocks) {
         None
     } else {
         Some(Token![unsafe](Span::call_site()))
     };
-    *function.block = parse_quote!({
-        struct __NoPanic;
-        #unsafe_extern extern "C" {
-            #[link_name = #message]
-            fn trigger() -> !;
-        }
-        impl ::core::ops::Drop for __NoPanic {
+    let enforce = mode::enforce_cfg(debug);
+    let abort = mode::abort_cfg(debug);
+    let (trigger, param, arg) = if generics.names.is_empty() {
+        let trigger = quote! {
+            #unsafe_extern extern "C" {
+                #[link_name = #message]
+                fn trigger() -> !;
+            }
+            unsafe {
+                trigger()
+            }
+        };
+        (trigger, None, None)
+    } else {
+        // The argument differs between instantiations, which keeps LLVM from
+        // merging their otherwise identical drops into one.
+        let trigger = quote! {
+            #unsafe_extern extern "C" {
+                #[link_name = #message]
+                fn trigger(instantiation: *const ()) -> !;
+            }
+            unsafe {
+                trigger(instantiation)
+            }
+        };
+        (
+            trigger,
+            Some(quote!(instantiation: *const ())),
+            Some(quote!(::core::any::type_name::<Self> as *const ())),
+        )
+    };
+    let listed = listed(key);
+    let (item, inline) = if generics.names.is_empty() {
+        (
+            quote! {
+                struct #name;
+                impl ::core::ops::Drop for #name
+            },
+            // Only emitted where the guard is dropped, so that a library's
+            // object code references `trigger` only where a panic survived.
+            quote!(#[inline]),
+        )
+    } else {
+        let GuardGenerics {
+            params,
+            idents,
+            types,
+            ..
+        } = generics;
+        (
+            quote! {
+                struct #name<#(#params),*>(
+                    ::core::marker::PhantomData<fn() -> (#(*const #types,)*)>,
+                );
+                impl<#(#params),*> ::core::ops::Drop for #name<#(#idents),*>
+            },
+            // Being generic, it is only emitted where the guard is dropped
+            // anyway. Kept out of line so that the linker reports the
+            // undefined symbol as referenced from this instantiation's drop,
+            // whose symbol name has the generic arguments.
+            quote!(#[inline(never)]),
+        )
+    };
+    quote! {
+        #item {
+            #inline
+            #[allow(unexpected_cfgs)]
             fn drop(&mut self) {
-                unsafe {
-                    trigger();
+                #[cfg(#enforce)]
+                {
+                    // Without unwinding the guard is never dropped, so the
+                    // link error cannot happen however much the code panics.
+                    // A warning rather than an error, since such a build used
+                    // to compile without one.
+                    #[cfg(panic = "abort")]
+                    {
+                        #[deprecated(
+                            note = "no_panic cannot detect panics in a build with panic = \"abort\"; check with panic = \"unwind\", and build this with --cfg no_panic=\"off\" or NO_PANIC=off"
+                        )]
+                        struct NotChecked;
+                        let _ = NotChecked;
+                    }
+                    // A failure listed in the baseline of cargo no-panic is
+                    // handled as in abort mode instead, so that what it is in
+                    // still links. Only the selected impl is ever emitted.
+                    const LISTED: bool = #listed;
+                    struct Listed<const LISTED: bool>;
+                    impl Listed<false> {
+                        #[inline(always)]
+                        fn fail(#param) -> ! {
+                            #trigger
+                        }
+                    }
+                    impl Listed<true> {
+                        #[inline(always)]
+                        #[allow(unused_variables)]
+                        fn fail(#param) -> ! {
+                            ::core::panic!("{}", #message)
+                        }
+                    }
+                    Listed::<LISTED>::fail(#arg);
                 }
+                #[cfg(#abort)]
+                ::core::panic!("{}", #message);
             }
         }
-        let __guard = __NoPanic;
-        let __result = (move || #ret {
-            #move_self
-            #(
-                #(#arg_attrs)*
-                let #arg_pat = #arg_val;
-            )*
-            #(#stmts)*
-        })();
-        ::core::mem::forget(__guard);
-        __result
-    });
-
-    quote!(#function)
+    }
 }
@@ -0,0 +1,114 @@
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use syn::parse::{Error, Result};
+
+// What the generated code does about a panic. The crate being compiled picks
+// one with `--cfg no_panic="..."`; otherwise the NO_PANIC environment variable
+// at the time no-panic was compiled decides, and otherwise it is Enforce.
+//
+// In builds with debug assertions, Enforce is further replaced by the debug
+// policy: the `debug` argument of the attribute, or else `--cfg
+// no_panic_debug="..."`, or else NO_PANIC_DEBUG, or else Enforce again.
+#[derive(Copy, Clone, PartialEq)]
+pub(crate) enum Mode {
+    // Fail to link.
+    Enforce,
+    // Abort at runtime, like noexcept.
+    Abort,
+    // Check nothing.
+    Off,
+}
+
+const MODES: [(&str, Mode); 3] = [
+    ("enforce", Mode::Enforce),
+    ("abort", Mode::Abort),
+    ("off", Mode::Off),
+];
+
+pub(crate) fn from_name(name: &str) -> Option<Mode> {
+    let (_name, mode) = MODES.iter().find(|(n, _mode)| *n == name)?;
+    Some(*mode)
+}
+
+fn name(mode: Mode) -> &'static str {
+    let (name, _mode) = MODES.iter().find(|(_name, m)| *m == mode).unwrap();
+    name
+}
+
+fn from_env(var: &str, value: Option<&str>) -> Result<Mode> {
+    let value = match value {
+        None | Some("") => return Ok(Mode::Enforce),
+        Some(value) => value,
+    };
+    from_name(value).ok_or_else(|| {
+        Error::new(
+            Span::call_site(),
+            format!(
+                "unsupported {} environment variable `{}` when no-panic was compiled; expected enforce, abort or off",
+                var, value,
+            ),
+        )
+    })
+}
+
+fn default() -> Result<Mode> {
+    from_env("NO_PANIC", option_env!("NO_PANIC"))
+}
+
+fn debug_default() -> Result<Mode> {
+    from_env("NO_PANIC_DEBUG", option_env!("NO_PANIC_DEBUG"))
+}
+
+// Report a bad NO_PANIC or NO_PANIC_DEBUG, rather than generating cfgs from it.
+pub(crate) fn check() -> Result<()> {
+    default()?;
+    debug_default()?;
+    Ok(())
+}
+
+// Cfg predicates that hold when the link error, or the abort, is the mode in
+// effect. `debug` is the debug policy given by the attribute, if any.
+
+pub(crate) fn enforce_cfg(debug: Option<Mode>) -> TokenStream {
+    let enforce = selected("no_panic", Mode::Enforce, default());
+    let debug_enforce = debug_selected(debug, Mode::Enforce);
+    quote!(all(#enforce, any(not(debug_assertions), #debug_enforce)))
+}
+
+pub(crate) fn abort_cfg(debug: Option<Mode>) -> TokenStream {
+    let abort = selected("no_panic", Mode::Abort, default());
+    let enforce = selected("no_panic", Mode::Enforce, default());
+    let debug_abort = debug_selected(debug, Mode::Abort);
+    quote!(any(#abort, all(#enforce, debug_assertions, #debug_abort)))
+}
+
+// Holds in a verification build, which also checks annotated functions that
+// nothing calls: one with `--cfg no_panic_verify`, or any build if
+// NO_PANIC_VERIFY was set when no-panic was compiled.
+pub(crate) fn verify_cfg() -> TokenStream {
+    match option_env!("NO_PANIC_VERIFY") {
+        None | Some("") => quote!(no_panic_verify),
+        Some(_) => quote!(all()),
+    }
+}
+
+fn debug_selected(debug: Option<Mode>, mode: Mode) -> TokenStream {
+    match debug {
+        Some(debug) if debug == mode => quote!(all()),
+        Some(_) => quote!(any()),
+        None => selected("no_panic_debug", mode, debug_default()),
+    }
+}
+
+// Holds when `--cfg <cfg>="<mode>"` is set, or no value of that cfg is set
+// and `mode` is the default.
+fn selected(cfg: &str, mode: Mode, default: Result<Mode>) -> TokenStream {
+    let cfg = Ident::new(cfg, Span::call_site());
+    let name = name(mode);
+    if default.ok() == Some(mode) {
+        let names = MODES.iter().map(|(name, _mode)| name);
+        quote!(any(#cfg = #name, not(any(#(#cfg = #names),*))))
+    } else {
+        quote!(#cfg = #name)
+    }
+}
@@ -0,0 +1,26 @@
+// How a method annotated through its impl is named in the linker error:
+// `Type::method`, or `<Type as Trait>::method`. cargo-no-panic includes this
+// file too, to name the functions it reads from the source the same way.
+
+use quote::ToTokens;
+use syn::{Path, Type};
+
+// Render a type the way it would be named in a path, without generic
+// arguments: `Vec<T>` is `Vec`, `&'a [u8]` is `&[u8]`.
+pub(crate) fn type_name(ty: &Type) -> String {
+    match ty {
+        Type::Path(ty) if ty.qself.is_none() => path_name(&ty.path),
+        Type::Reference(ty) => format!("&{}", type_name(&ty.elem)),
+        Type::Slice(ty) => format!("[{}]", type_name(&ty.elem)),
+        Type::Group(ty) => type_name(&ty.elem),
+        Type::Paren(ty) => type_name(&ty.elem),
+        _ => ty.to_token_stream().to_string(),
+    }
+}
+
+pub(crate) fn path_name(path: &Path) -> String {
+    match path.segments.last() {
+        Some(segment) => segment.ident.to_string(),
+        None => path.to_token_stream().to_string(),
+    }
+}
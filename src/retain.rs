@@ -0,0 +1,335 @@
+// Make rustc emit annotated functions that nothing calls, so that they are
+// checked too. A `#[used]` static holding a pointer to the function forces
+// its codegen, and with it the reference to `trigger` if a panic survives.
+//
+// The static is placed inside of the function body, where it can only name
+// the function by a path that does not involve `Self` or generic parameters
+// of an enclosing item. A method of a non-generic impl has such a path, and so
+// does a function of an annotated module. A function given to the attribute
+// directly might be a free function or an associated function of some impl;
+// the two look the same. For those, the static can instead point to a copy of
+// the function nested beside it, which requires that the function does not
+// use `self`, `Self` or generic parameters of an impl. Since that cannot be
+// told from here, the copy is only made when asked for with `verify`, or
+// `instantiate` which needs it anyway.
+//
+// A generic function is retained once for each list of generic arguments in
+// `instantiate(...)`. Its copies are not generic: each one gets the generic
+// parameters bound by local type aliases and consts, and its own linker error
+// naming the arguments.
+
+use crate::args::{Args, Instantiate};
+use crate::expand_no_panic;
+use proc_macro2::{Delimiter, Ident, Spacing, Span, TokenStream, TokenTree};
+use quote::{quote, ToTokens};
+use syn::parse::{Error, Result};
+use syn::punctuated::Punctuated;
+use syn::{
+    parse_quote, FnArg, FnModifiers, GenericArgument, GenericParam, ItemFn, Path, Token, Type,
+    Visibility,
+};
+
+pub(crate) enum Context<'a> {
+    // Directly annotated function.
+    Fn,
+    // Function in an annotated module.
+    Mod,
+    // Method in an annotated impl block.
+    Impl {
+        self_ty: &'a Type,
+        trait_: Option<&'a Path>,
+        generic: bool,
+    },
+    // Default method of a trait, whose `Self` is generic.
+    Trait,
+    // Copy of a function made here, which is not retained again.
+    Copy,
+}
+
+pub(crate) enum Retain {
+    // Path of a method, to retain directly.
+    Path(TokenStream),
+    // The function before expansion, to retain a copy of.
+    Copy(Box<ItemFn>),
+}
+
+// Decide before expansion how `function` can be retained.
+pub(crate) fn prepare(function: &ItemFn, context: &Context, args: &Args) -> Result<Option<Retain>> {
+    let sig = &function.sig;
+    let params = sig
+        .generics
+        .params
+        .iter()
+        .filter(|param| !matches!(param, GenericParam::Lifetime(_)))
+        .count();
+    let instantiate = args.instantiate.as_ref();
+    if let Some(instantiate) = instantiate {
+        if params == 0 {
+            return Err(Error::new(
+                instantiate.span,
+                "instantiate is only supported on a generic function",
+            ));
+        }
+        for list in &instantiate.args {
+            if list.len() != params {
+                return Err(Error::new(
+                    instantiate.span,
+                    format!(
+                        "instantiate expects {} generic argument{} in each instantiation, for the type and const parameters of `{}`",
+                        params,
+                        if params == 1 { "" } else { "s" },
+                        sig.ident,
+                    ),
+                ));
+            }
+        }
+    }
+
+    let unsupported = |reason: &str| match instantiate {
+        Some(instantiate) => Err(Error::new(
+            instantiate.span,
+            format!("instantiate is not supported on {}", reason),
+        )),
+        None if args.verify => Err(Error::new(
+            Span::call_site(),
+            format!("verify is not supported on {}", reason),
+        )),
+        None => Ok(None),
+    };
+    if sig.asyncness.is_some() {
+        return unsupported("an async fn");
+    }
+    if sig.variadic.is_some() {
+        return unsupported("a variadic function");
+    }
+    if sig.inputs.iter().any(|input| match input {
+        FnArg::Typed(arg) => mentions(arg.ty.to_token_stream(), "impl"),
+        FnArg::Receiver(_) => false,
+    }) {
+        return unsupported("a function with `impl Trait` parameters");
+    }
+    if params > 0 && instantiate.is_none() {
+        return Ok(None);
+    }
+
+    let ident = &sig.ident;
+    let path = match context {
+        Context::Impl {
+            self_ty,
+            trait_: Some(trait_),
+            generic: false,
+        } => Some(quote!(<#self_ty as #trait_>::#ident)),
+        Context::Impl {
+            self_ty,
+            trait_: None,
+            generic: false,
+        } => Some(quote!(<#self_ty>::#ident)),
+        Context::Mod => Some(quote!(self::#ident)),
+        Context::Fn | Context::Impl { .. } | Context::Trait | Context::Copy => None,
+    };
+    let copyable = match context {
+        Context::Fn => args.verify || instantiate.is_some(),
+        Context::Mod | Context::Impl { generic: false, .. } => true,
+        Context::Impl { .. } | Context::Trait | Context::Copy => false,
+    } && sig.receiver().is_none()
+        && !mentions(sig.to_token_stream(), "Self")
+        && !mentions(function.block.to_token_stream(), "Self");
+
+    // Prefer the method itself, unless a copy is needed to name the generic
+    // arguments in the linker error.
+    if let Some(path) = path {
+        if params == 0 || !copyable {
+            return Ok(Some(Retain::Path(path)));
+        }
+    }
+    if copyable {
+        return Ok(Some(Retain::Copy(Box::new(function.clone()))));
+    }
+    match context {
+        Context::Fn | Context::Mod => {
+            unsupported("a method unless #[no_panic] is on its impl block")
+        }
+        Context::Impl { .. } | Context::Trait | Context::Copy => {
+            unsupported("a method of a generic impl or of a trait")
+        }
+    }
+}
+
+// Apply to the expanded function, named `name` in the linker error. `cfg` is
+// when to retain it.
+pub(crate) fn apply(
+    retain: Retain,
+    function: &mut ItemFn,
+    name: &str,
+    args: &Args,
+    cfg: &TokenStream,
+) -> Result<()> {
+    let instantiations = match &args.instantiate {
+        Some(Instantiate { args, .. }) => args.iter().map(Some).collect(),
+        None => vec![None],
+    };
+
+    let mut retained = Vec::new();
+    match retain {
+        Retain::Path(path) => {
+            let pointers = instantiations.iter().map(|generics| match generics {
+                Some(generics) => quote!(#path::<#generics>),
+                None => path.clone(),
+            });
+            retained.push(statics(pointers));
+        }
+        Retain::Copy(original) => {
+            let mut args = args.clone();
+            args.verify = false;
+            args.instantiate = None;
+            for generics in instantiations {
+                let (name, bindings) = match generics {
+                    Some(generics) => (
+                        format!("{}::<{}>", name, display(generics.to_token_stream())),
+                        bindings(&original, generics),
+                    ),
+                    None => (name.to_owned(), TokenStream::new()),
+                };
+                let mut copy = copy(&original);
+                expand_no_panic(&mut copy, &name, &args, &Context::Copy)?;
+                let ident = &copy.sig.ident;
+                let statics = statics([quote!(#ident)]);
+                retained.push(quote! {
+                    #bindings
+                    #copy
+                    #statics
+                });
+            }
+        }
+    }
+
+    let stmts = &mut function.block.stmts;
+    stmts.insert(
+        0,
+        parse_quote! {
+            #[allow(unexpected_cfgs)]
+            const _: () = {
+                #(
+                    #[cfg(#cfg)]
+                    const _: () = {
+                        #retained
+                    };
+                )*
+            };
+        },
+    );
+    Ok(())
+}
+
+// `#[used]` statics holding the function pointers.
+fn statics(pointers: impl IntoIterator<Item = TokenStream>) -> TokenStream {
+    let pointers: Vec<TokenStream> = pointers.into_iter().collect();
+    let len = pointers.len();
+    quote! {
+        #[allow(dead_code)]
+        struct __NoPanicRetain(*const ());
+        unsafe impl ::core::marker::Sync for __NoPanicRetain {}
+        #[used]
+        static __NO_PANIC_RETAIN: [__NoPanicRetain; #len] = [
+            #(__NoPanicRetain(#pointers as *const ()),)*
+        ];
+    }
+}
+
+// The function, renamed, and without attributes other than lints and generic
+// parameters other than lifetimes.
+fn copy(original: &ItemFn) -> ItemFn {
+    let mut copy = original.clone();
+    copy.attrs.retain(|attr| {
+        let path = attr.path();
+        ["allow", "expect", "warn", "deny", "forbid"]
+            .iter()
+            .any(|lint| path.is_ident(lint))
+    });
+    copy.vis = Visibility::Inherited;
+    copy.modifiers = FnModifiers::default();
+    copy.sig.ident = Ident::new("__no_panic", Span::call_site());
+    copy.sig.generics.params = copy
+        .sig
+        .generics
+        .params
+        .into_iter()
+        .filter(|param| matches!(param, GenericParam::Lifetime(_)))
+        .collect();
+    copy
+}
+
+// Local items that give the generic parameters of `original` their values.
+fn bindings(original: &ItemFn, generics: &Punctuated<GenericArgument, Token![,]>) -> TokenStream {
+    let params = original
+        .sig
+        .generics
+        .params
+        .iter()
+        .filter(|param| !matches!(param, GenericParam::Lifetime(_)));
+    let mut bindings = TokenStream::new();
+    for (param, arg) in params.zip(generics) {
+        match param {
+            GenericParam::Type(param) => {
+                let ident = &param.ident;
+                bindings.extend(quote!(type #ident = #arg;));
+            }
+            GenericParam::Const(param) => {
+                let ident = &param.ident;
+                let ty = &param.ty;
+                bindings.extend(quote!(const #ident: #ty = #arg;));
+            }
+            GenericParam::Lifetime(_) => {}
+        }
+    }
+    bindings
+}
+
+// Generic arguments written compactly for the linker error: `Vec<u8>, 4`.
+fn display(tokens: TokenStream) -> String {
+    let mut string = String::new();
+    let mut word = false;
+    for token in tokens {
+        match token {
+            TokenTree::Group(group) => {
+                let (open, close) = match group.delimiter() {
+                    Delimiter::Parenthesis => ("(", ")"),
+                    Delimiter::Bracket => ("[", "]"),
+                    Delimiter::Brace => ("{ ", " }"),
+                    Delimiter::None => ("", ""),
+                };
+                string.push_str(open);
+                string.push_str(&display(group.stream()));
+                string.push_str(close);
+                word = false;
+            }
+            TokenTree::Punct(punct) => {
+                let ch = punct.as_char();
+                if matches!(ch, '+' | '=') || ch == '-' && punct.spacing() == Spacing::Joint {
+                    string.push(' ');
+                }
+                string.push(ch);
+                if matches!(ch, ',' | ';' | '+' | '=') || ch == '>' && string.ends_with("->") {
+                    string.push(' ');
+                }
+                word = false;
+            }
+            TokenTree::Ident(_) | TokenTree::Literal(_) => {
+                if word {
+                    string.push(' ');
+                }
+                string.push_str(&token.to_string());
+                word = true;
+            }
+        }
+    }
+    string
+}
+
+fn mentions(tokens: TokenStream, word: &str) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => ident == word,
+        TokenTree::Group(group) => mentions(group.stream(), word),
+        _ => false,
+    })
+}
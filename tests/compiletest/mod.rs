@@ -1,5 +1,6 @@
 use std::fs;
 use std::io;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::sync::Once;
 
@@ -14,7 +15,185 @@ pub fn setup() {
     });
 }
 
-pub fn contains_panic(name: &str, code: &str) -> bool {
+pub fn contains_panic(name: &str, code: &str, cfgs: &[&str], codegen: &[&str]) -> bool {
+    let tempdir = compile(name, code, cfgs, codegen, "asm").unwrap();
+    let asm = tempdir.join(format!("{}.s", name));
+    let asm = fs::read_to_string(asm).unwrap();
+    // Only a reference to the symbol counts. In abort mode the same message
+    // is there as string data, to be printed at runtime.
+    asm.lines().any(|line| {
+        let line = line.trim_start();
+        !line.starts_with(".ascii")
+            && !line.starts_with(".asciz")
+            && !line.starts_with(".string")
+            && line.contains(MESSAGE)
+    })
+}
+
+// Returns the text of each no-panic error that the code refers to, from the
+// subject on. The line and column of a location depend on how the code was
+// stringified, so they are left out, after checking that the location of a
+// function is its `fn` token, and the file is given relative to the code's
+// directory.
+pub fn panic_messages(name: &str, code: &str, cfgs: &[&str], codegen: &[&str]) -> Vec<String> {
+    let tempdir = compile(name, code, cfgs, codegen, "asm").unwrap();
+    let asm = tempdir.join(format!("{}.s", name));
+    let asm = fs::read_to_string(asm).unwrap();
+    let rs = tempdir.join(format!("{}.rs", name));
+    let source = fs::read_to_string(&rs).unwrap();
+    let mut messages = Vec::new();
+    for symbol in symbols(&asm) {
+        let subject = &symbol[MESSAGE.len()..];
+        let mut message = String::new();
+        for line in subject.trim_end().lines() {
+            if let Some(location) = line.strip_prefix("  location: ") {
+                let mut pieces = location.rsplitn(3, ':');
+                let column: usize = pieces.next().unwrap().parse().unwrap();
+                let line: usize = pieces.next().unwrap().parse().unwrap();
+                let file = pieces.next().unwrap();
+                assert_eq!(Path::new(file), rs);
+                if subject.starts_with("function") {
+                    let text = source.lines().nth(line - 1).unwrap();
+                    assert!(text[column - 1..].starts_with("fn "), "{}", location);
+                }
+                message.push_str("  location: ");
+                message.push_str(name);
+                message.push_str(".rs");
+            } else {
+                message.push_str(line);
+            }
+            message.push('\n');
+        }
+        if !messages.contains(&message) {
+            messages.push(message);
+        }
+    }
+    messages
+}
+
+// The no-panic errors that the assembly refers to, from the marker on.
+fn symbols(asm: &str) -> Vec<String> {
+    let mut symbols = Vec::new();
+    for line in asm.lines() {
+        let line = line.trim_start();
+        if line.starts_with('.') {
+            continue;
+        }
+        let Some(start) = line.find(MESSAGE) else {
+            continue;
+        };
+        let end = line.rfind('"').unwrap();
+        symbols.push(unescape(&line[start..end]));
+    }
+    symbols
+}
+
+// A symbol as quoted in assembly: `\n`, `\"` and `\\`.
+fn unescape(quoted: &str) -> String {
+    let mut unescaped = String::new();
+    let mut chars = quoted.chars();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\\' => match chars.next() {
+                Some('n') => unescaped.push('\n'),
+                Some(ch) => unescaped.push(ch),
+                None => {}
+            },
+            ch => unescaped.push(ch),
+        }
+    }
+    unescaped
+}
+
+// Runs the program and returns whether it aborted after printing the message.
+pub fn aborts(name: &str, code: &str, cfgs: &[&str], codegen: &[&str]) -> bool {
+    let tempdir = compile(name, code, cfgs, codegen, "link").unwrap();
+    let output = Command::new(tempdir.join(name))
+        .output()
+        .expect("failed to execute test program");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    // A panic that unwinds out of main exits with 101 instead.
+    !output.status.success() && output.status.code() != Some(101) && stderr.contains(MESSAGE)
+}
+
+// Lists each no-panic error of the code in NO_PANIC_BASELINE, the way cargo
+// no-panic does for its baseline, then runs the program and returns whether
+// it aborted after printing the message, now that it links.
+pub fn aborts_listed(name: &str, code: &str, cfgs: &[&str], codegen: &[&str]) -> bool {
+    let tempdir = compile(name, code, cfgs, codegen, "asm").unwrap();
+    let asm = tempdir.join(format!("{}.s", name));
+    let asm = fs::read_to_string(asm).unwrap();
+    let mut keys = String::new();
+    for symbol in symbols(&asm) {
+        let field = |key: &str| {
+            let prefix = format!("  {}: ", key);
+            let line = symbol.lines().find(|line| line.starts_with(&prefix));
+            line.unwrap()[prefix.len()..].to_owned()
+        };
+        keys.push_str(&field("module"));
+        keys.push(' ');
+        keys.push_str(&field("location"));
+        keys.push('\n');
+    }
+    assert!(!keys.is_empty(), "expected {} to fail to link", name);
+
+    let (tempdir, mut rustc) = rustc(name, code, cfgs, codegen, "link");
+    let output = rustc
+        .env("NO_PANIC_BASELINE", keys)
+        .output()
+        .expect("failed to execute rustc");
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr),
+    );
+    let output = Command::new(tempdir.join(name))
+        .output()
+        .expect("failed to execute test program");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    !output.status.success() && output.status.code() != Some(101) && stderr.contains(MESSAGE)
+}
+
+// Returns rustc's warnings, having checked that compiling succeeds once they
+// are no longer denied.
+pub fn compile_warning(name: &str, code: &str, cfgs: &[&str], codegen: &[&str]) -> String {
+    let (_tempdir, mut rustc) = rustc(name, code, cfgs, codegen, "asm");
+    let output = rustc
+        .arg("--cap-lints=warn")
+        .output()
+        .expect("failed to execute rustc");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{}", stderr);
+    stderr.into_owned()
+}
+
+const MESSAGE: &str = "ERROR[no-panic]: detected panic in ";
+
+fn compile(
+    name: &str,
+    code: &str,
+    cfgs: &[&str],
+    codegen: &[&str],
+    emit: &str,
+) -> Result<PathBuf, String> {
+    let (tempdir, mut rustc) = rustc(name, code, cfgs, codegen, emit);
+    let output = rustc.output().expect("failed to execute rustc");
+    if output.status.success() {
+        Ok(tempdir)
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.contains("error"), "rustc failed without an error");
+        Err(stderr.into_owned())
+    }
+}
+
+fn rustc(
+    name: &str,
+    code: &str,
+    cfgs: &[&str],
+    codegen: &[&str],
+    emit: &str,
+) -> (PathBuf, Command) {
     let tempdir = scratch::path("no-panic").join(name);
     match fs::create_dir(&tempdir) {
         Ok(()) => {}
@@ -23,20 +202,29 @@ pub fn contains_panic(name: &str, code: &str) -> bool {
     }
 
     let prelude = stringify! {
+        #[allow(unused_imports)]
         use no_panic::no_panic;
     };
 
     let rs = tempdir.join(format!("{}.rs", name));
     fs::write(&rs, format!("{}{}", prelude, code)).unwrap();
 
-    let status = Command::new("rustc")
+    let mut rustc = Command::new("rustc");
+    for cfg in cfgs {
+        rustc.arg("--cfg").arg(cfg);
+    }
+    for option in codegen {
+        // As stringified by the macros below, `panic = abort`.
+        rustc.arg("-C").arg(option.replace(' ', ""));
+    }
+    rustc
         .arg("--crate-name")
         .arg(name)
         .arg(rs)
         .arg("--edition=2018")
         .arg("-C")
         .arg("opt-level=3")
-        .arg("--emit=asm")
+        .arg(format!("--emit={}", emit))
         .arg("--out-dir")
         .arg(&tempdir)
         .arg("--extern")
@@ -46,18 +234,16 @@ pub fn contains_panic(name: &str, code: &str) -> bool {
             extension = std::env::consts::DLL_EXTENSION,
         ))
         .arg("-D")
-        .arg("warnings")
-        .status()
-        .expect("failed to execute rustc");
-    assert!(status.success());
-
-    let asm = tempdir.join(format!("{}.s", name));
-    let asm = fs::read_to_string(asm).unwrap();
-    asm.contains("detected panic in function")
+        .arg("warnings");
+    (tempdir, rustc)
 }
 
 macro_rules! assert_no_panic {
-    ($(mod $name:ident { $($content:tt)* })*) => {
+    ($(
+        $(#[with_cfg($($cfg:tt)*)])*
+        $(#[with_codegen($($codegen:tt)*)])*
+        mod $name:ident { $($content:tt)* }
+    )*) => {
         mod no_panic {
             use crate::compiletest;
             $(
@@ -66,7 +252,9 @@ macro_rules! assert_no_panic {
                     compiletest::setup();
                     let name = stringify!($name);
                     let content = stringify!($($content)*);
-                    assert!(!compiletest::contains_panic(name, content));
+                    let cfgs: &[&str] = &[$(stringify!($($cfg)*)),*];
+                    let codegen: &[&str] = &[$(stringify!($($codegen)*)),*];
+                    assert!(!compiletest::contains_panic(name, content, cfgs, codegen));
                 }
             )*
         }
@@ -74,7 +262,11 @@ macro_rules! assert_no_panic {
 }
 
 macro_rules! assert_link_error {
-    ($(mod $name:ident { $($content:tt)* })*) => {
+    ($(
+        $(#[with_cfg($($cfg:tt)*)])*
+        $(#[with_codegen($($codegen:tt)*)])*
+        mod $name:ident { $($content:tt)* } $(=> $message:literal)?
+    )*) => {
         mod link_error {
             use crate::compiletest;
             $(
@@ -83,7 +275,83 @@ macro_rules! assert_link_error {
                     compiletest::setup();
                     let name = stringify!($name);
                     let content = stringify!($($content)*);
-                    assert!(compiletest::contains_panic(name, content));
+                    let cfgs: &[&str] = &[$(stringify!($($cfg)*)),*];
+                    let codegen: &[&str] = &[$(stringify!($($codegen)*)),*];
+                    assert!(compiletest::contains_panic(name, content, cfgs, codegen));
+                    $(
+                        let messages = compiletest::panic_messages(name, content, cfgs, codegen);
+                        assert!(messages.iter().any(|message| message == $message), "{:#?}", messages);
+                    )?
+                }
+            )*
+        }
+    };
+}
+
+macro_rules! assert_abort {
+    ($(
+        $(#[with_cfg($($cfg:tt)*)])*
+        $(#[with_codegen($($codegen:tt)*)])*
+        mod $name:ident { $($content:tt)* }
+    )*) => {
+        mod abort {
+            use crate::compiletest;
+            $(
+                #[test]
+                fn $name() {
+                    compiletest::setup();
+                    let name = stringify!($name);
+                    let content = stringify!($($content)*);
+                    let cfgs: &[&str] = &[$(stringify!($($cfg)*)),*];
+                    let codegen: &[&str] = &[$(stringify!($($codegen)*)),*];
+                    assert!(compiletest::aborts(name, content, cfgs, codegen));
+                }
+            )*
+        }
+    };
+}
+
+macro_rules! assert_compile_warning {
+    ($(
+        $(#[with_cfg($($cfg:tt)*)])*
+        $(#[with_codegen($($codegen:tt)*)])*
+        mod $name:ident { $($content:tt)* } => $message:literal
+    )*) => {
+        mod compile_warning {
+            use crate::compiletest;
+            $(
+                #[test]
+                fn $name() {
+                    compiletest::setup();
+                    let name = stringify!($name);
+                    let content = stringify!($($content)*);
+                    let cfgs: &[&str] = &[$(stringify!($($cfg)*)),*];
+                    let codegen: &[&str] = &[$(stringify!($($codegen)*)),*];
+                    let stderr = compiletest::compile_warning(name, content, cfgs, codegen);
+                    assert!(stderr.contains($message), "{}", stderr);
+                }
+            )*
+        }
+    };
+}
+
+macro_rules! assert_listed_abort {
+    ($(
+        $(#[with_cfg($($cfg:tt)*)])*
+        $(#[with_codegen($($codegen:tt)*)])*
+        mod $name:ident { $($content:tt)* }
+    )*) => {
+        mod listed_abort {
+            use crate::compiletest;
+            $(
+                #[test]
+                fn $name() {
+                    compiletest::setup();
+                    let name = stringify!($name);
+                    let content = stringify!($($content)*);
+                    let cfgs: &[&str] = &[$(stringify!($($cfg)*)),*];
+                    let codegen: &[&str] = &[$(stringify!($($codegen)*)),*];
+                    assert!(compiletest::aborts_listed(name, content, cfgs, codegen));
                 }
             )*
         }
@@ -252,6 +252,495 @@ assert_no_panic![
 
         fn main() {}
     }
+
+    mod test_trait_default_method {
+        trait Trait {
+            #[no_panic]
+            fn demo(&self) -> &'static str {
+                "test"
+            }
+        }
+
+        impl Trait for i32 {}
+
+        fn main() {
+            println!("{}", 0i32.demo());
+        }
+    }
+
+    mod test_trait {
+        #[no_panic]
+        trait Codec {
+            fn decode(&self, bytes: &[u8]) -> Option<u8>;
+
+            fn name(&self) -> &'static str {
+                "codec"
+            }
+        }
+
+        struct First;
+
+        impl Codec for First {
+            fn decode(&self, bytes: &[u8]) -> Option<u8> {
+                bytes.first().copied()
+            }
+        }
+
+        fn main() {
+            println!("{} {:?}", First.name(), First.decode(b"input string"));
+        }
+    }
+
+    mod test_trait_contract {
+        #[no_panic(contract)]
+        trait Codec {
+            fn decode(&self, bytes: &[u8]) -> Option<u8>;
+
+            fn name(&self) -> &'static str {
+                "codec"
+            }
+        }
+
+        struct First;
+
+        #[no_panic(contract)]
+        impl Codec for First {
+            fn decode(&self, bytes: &[u8]) -> Option<u8> {
+                bytes.first().copied()
+            }
+        }
+
+        fn main() {
+            let codec: &dyn Codec = &First;
+            println!("{} {:?}", codec.name(), codec.decode(b"input string"));
+        }
+    }
+
+    mod test_impl_block {
+        struct S {
+            data: [u8; 24],
+        }
+
+        #[no_panic]
+        impl S {
+            fn first(&self) -> Option<&u8> {
+                self.data.first()
+            }
+
+            #[no_panic(skip)]
+            fn unchecked(&self, i: usize) -> u8 {
+                self.data[i]
+            }
+        }
+
+        fn main() {
+            let s = S { data: [0u8; 24] };
+            println!("{:?} {}", s.first(), s.unchecked(30));
+        }
+    }
+
+    mod test_trait_impl_block {
+        struct S;
+
+        #[no_panic]
+        impl Iterator for S {
+            type Item = u8;
+
+            fn next(&mut self) -> Option<u8> {
+                None
+            }
+        }
+
+        fn main() {
+            println!("{:?}", S.next());
+        }
+    }
+
+    mod test_inline_module {
+        #[no_panic]
+        mod decode {
+            pub struct Decoder {
+                pub buffer: [u8; 16],
+            }
+
+            impl Decoder {
+                pub fn peek(&self) -> Option<u8> {
+                    self.buffer.first().copied()
+                }
+            }
+
+            pub trait Peek {
+                fn peek_twice(&self) -> Option<(u8, u8)> {
+                    None
+                }
+            }
+
+            impl Peek for Decoder {}
+
+            pub mod util {
+                pub fn len(bytes: &[u8]) -> usize {
+                    bytes.len()
+                }
+
+                #[no_panic(skip)]
+                pub fn at(bytes: &[u8], i: usize) -> u8 {
+                    bytes[i]
+                }
+            }
+        }
+
+        use self::decode::Peek;
+
+        fn main() {
+            let decoder = decode::Decoder { buffer: [0u8; 16] };
+            println!("{:?}", decoder.peek());
+            println!("{:?}", decoder.peek_twice());
+            println!("{}", decode::util::len(&decoder.buffer));
+            println!("{}", decode::util::at(&decoder.buffer, 20));
+        }
+    }
+
+    mod test_block {
+        fn demo(bytes: &[u8]) -> Result<u8, ()> {
+            let first = *bytes.first().expect("nonempty");
+            let mut sum = first;
+            for byte in bytes {
+                no_panic::block! {
+                    if *byte == 0 {
+                        continue;
+                    }
+                    if *byte == 1 {
+                        break;
+                    }
+                    if *byte == 2 {
+                        return Err(());
+                    }
+                    let byte = bytes.get(*byte as usize).ok_or(())?;
+                    sum = sum.wrapping_add(*byte);
+                }
+            }
+            Ok(sum)
+        }
+
+        fn main() {
+            println!("{:?}", demo(b"input string"));
+        }
+    }
+
+    mod test_closure {
+        fn main() {
+            let offset = std::env::args().count();
+            let mut calls = 0;
+            let mut add = no_panic::closure!(|i: usize| -> Option<usize> {
+                calls += 1;
+                if i == 0 {
+                    return None;
+                }
+                let sum = i.checked_add(offset)?;
+                Some(sum)
+            });
+            println!("{:?} {:?}", add(0), add(1));
+            println!("{}", calls);
+
+            let words = ["input", "string"];
+            let lengths: Vec<usize> = words.iter().map(no_panic::closure!(|w| w.len())).collect();
+            println!("{:?}", lengths);
+        }
+    }
+
+    mod test_message_args {
+        #[no_panic(msg = "called while holding the device lock", id = "NET-1234")]
+        fn demo(s: &str) -> &str {
+            s.trim()
+        }
+
+        #[no_panic(owner = "net-team")]
+        impl Iterator for Counter {
+            type Item = u8;
+
+            #[no_panic(msg = "called from the interrupt handler")]
+            fn next(&mut self) -> Option<u8> {
+                self.0 = self.0.checked_add(1)?;
+                Some(self.0)
+            }
+        }
+
+        struct Counter(u8);
+
+        fn main() {
+            println!("{} {:?}", demo(" input string "), Counter(0).next());
+        }
+    }
+
+    mod test_const_fn {
+        #[no_panic]
+        const fn demo(i: u32) -> u32 {
+            if i == 0 {
+                return 0;
+            }
+            i / 2
+        }
+
+        const HALF: u32 = demo(2);
+
+        fn main() {
+            println!("{}", demo(HALF));
+        }
+    }
+
+    mod test_async_fn {
+        use std::future::Future;
+        use std::pin::{pin, Pin};
+        use std::task::{Context, Poll, Waker};
+
+        struct YieldNow(bool);
+
+        impl Future for YieldNow {
+            type Output = ();
+            fn poll(mut self: Pin<&mut Self>, _cx: &mut Context) -> Poll<()> {
+                if self.0 {
+                    Poll::Ready(())
+                } else {
+                    self.0 = true;
+                    Poll::Pending
+                }
+            }
+        }
+
+        #[no_panic]
+        async fn demo(s: &str) -> Result<usize, ()> {
+            YieldNow(false).await;
+            if s.is_empty() {
+                return Err(());
+            }
+            let n = Ok::<usize, ()>(s.len())?;
+            YieldNow(false).await;
+            Ok(n)
+        }
+
+        fn main() {
+            let mut cx = Context::from_waker(Waker::noop());
+            let mut future = pin!(demo("input string"));
+            println!("{:?}", future.as_mut().poll(&mut cx));
+        }
+    }
+
+    mod test_async_poll {
+        use std::future::Future;
+        use std::pin::{pin, Pin};
+        use std::task::{Context, Poll, Waker};
+
+        struct Ready<'a>(&'a [u8]);
+
+        impl Future for Ready<'_> {
+            type Output = Option<u8>;
+            fn poll(self: Pin<&mut Self>, _cx: &mut Context) -> Poll<Option<u8>> {
+                Poll::Ready(self.0.first().copied())
+            }
+        }
+
+        #[no_panic(poll)]
+        async fn demo<T: Copy>(bytes: &[u8], t: T) -> (Option<u8>, T) {
+            let first = Ready(bytes).await;
+            (first, t)
+        }
+
+        fn main() {
+            let mut cx = Context::from_waker(Waker::noop());
+            let mut future = pin!(demo(b"input string", 1u8));
+            println!("{:?}", future.as_mut().poll(&mut cx));
+        }
+    }
+
+    mod test_granular {
+        use std::future::Future;
+        use std::pin::pin;
+        use std::task::{Context, Waker};
+
+        #[no_panic(granular)]
+        fn demo(bytes: &[u8]) -> Result<u8, ()> {
+            let Some(first) = bytes.first() else {
+                return Err(());
+            };
+            let second = bytes.get(1).copied().ok_or(())?;
+            if *first == 0 {
+                return Ok(second);
+            }
+            Ok(first.wrapping_add(second))
+        }
+
+        #[no_panic(granular)]
+        fn is_digit(byte: u8) -> bool {
+            let byte = byte.to_ascii_lowercase();
+            matches!(byte, b'0'..=b'9')
+        }
+
+        #[no_panic(granular)]
+        async fn later(s: &str) -> Result<usize, ()> {
+            async {}.await;
+            let n = s.len().checked_sub(1).ok_or(())?;
+            Ok(n)
+        }
+
+        fn main() {
+            println!("{:?}", demo(b"input string"));
+            println!("{}", is_digit(b'7'));
+            let mut cx = Context::from_waker(Waker::noop());
+            let mut future = pin!(later("input string"));
+            println!("{:?}", future.as_mut().poll(&mut cx));
+        }
+    }
+
+    #[with_cfg(no_panic_verify)]
+    mod test_unused {
+        pub struct S(u8);
+
+        #[no_panic]
+        impl S {
+            fn new() -> Self {
+                S(0)
+            }
+
+            fn get(&self) -> u8 {
+                self.0
+            }
+        }
+
+        #[no_panic]
+        impl Clone for S {
+            fn clone(&self) -> Self {
+                Self::new()
+            }
+        }
+
+        #[no_panic(verify)]
+        fn demo(mut x: u8) -> u8 {
+            x = x.wrapping_add(1);
+            x
+        }
+
+        #[no_panic(verify)]
+        unsafe fn read(p: *const u8) -> u8 {
+            *p
+        }
+
+        #[no_panic]
+        mod util {
+            pub fn halve(x: u8) -> u8 {
+                x / 2
+            }
+        }
+
+        fn main() {}
+    }
+
+    #[with_cfg(no_panic_verify)]
+    mod test_unused_generic_impl {
+        pub struct S<T>(T);
+
+        impl<T> S<T> {
+            #[no_panic]
+            pub fn size() -> usize {
+                core::mem::size_of::<T>()
+            }
+        }
+
+        fn main() {}
+    }
+
+    #[with_cfg(no_panic_verify)]
+    mod test_instantiate {
+        pub struct S(u8);
+
+        #[no_panic]
+        impl S {
+            #[no_panic(instantiate(u16, u32))]
+            fn get<T: From<u8>>(&self) -> T {
+                T::from(self.0)
+            }
+        }
+
+        #[no_panic(instantiate(<u8, 2>, <u64, 4>))]
+        fn first<T: Copy, const N: usize>(array: &[T; N]) -> Option<&T> {
+            array.first()
+        }
+
+        fn main() {}
+    }
+
+    #[with_cfg(no_panic = "off")]
+    mod test_mode_off {
+        #[no_panic]
+        fn demo(s: &str) -> &str {
+            &s[1..]
+        }
+
+        fn main() {
+            println!("{}", demo("\u{1f980}input string"));
+        }
+    }
+
+    #[with_cfg(no_panic = "off")]
+    #[with_codegen(panic = abort)]
+    mod test_mode_off_panic_abort {
+        #[no_panic]
+        fn demo(s: &str) -> &str {
+            &s[1..]
+        }
+
+        fn main() {
+            println!("{}", demo("\u{1f980}input string"));
+        }
+    }
+
+    #[with_codegen(debug-assertions = on)]
+    mod test_debug_off {
+        #[no_panic(debug = "off")]
+        fn demo(s: &str) -> &str {
+            &s[1..]
+        }
+
+        fn main() {
+            println!("{}", demo("\u{1f980}input string"));
+        }
+    }
+
+    #[with_codegen(debug-assertions = on)]
+    mod test_debug_abort {
+        #[no_panic(debug = "abort")]
+        fn demo(s: &str) -> &str {
+            &s[1..]
+        }
+
+        fn main() {
+            println!("{}", demo("\u{1f980}input string"));
+        }
+    }
+
+    #[with_cfg(no_panic_debug = "off")]
+    #[with_codegen(debug-assertions = on)]
+    mod test_no_panic_debug_off {
+        #[no_panic]
+        fn demo(s: &str) -> &str {
+            &s[1..]
+        }
+
+        fn main() {
+            println!("{}", demo("\u{1f980}input string"));
+        }
+    }
+
+    #[with_cfg(no_panic = "abort")]
+    mod test_mode_abort {
+        #[no_panic]
+        fn demo(s: &str) -> &str {
+            &s[1..]
+        }
+
+        fn main() {
+            println!("{}", demo("\u{1f980}input string"));
+        }
+    }
 ];
 
 assert_link_error![
@@ -265,4 +754,361 @@ assert_link_error![
             println!("{}", demo("\u{1f980}input string"));
         }
     }
+
+    mod test_impl_block_bad {
+        struct S {
+            data: [u8; 24],
+        }
+
+        #[no_panic]
+        impl S {
+            fn get(&self, i: usize) -> u8 {
+                self.data[i]
+            }
+        }
+
+        fn main() {
+            let s = S { data: [0u8; 24] };
+            println!("{}", s.get(30));
+        }
+    } => "function `S::get`
+  module: test_impl_block_bad
+  location: test_impl_block_bad.rs
+"
+
+    mod test_method_bad {
+        struct S {
+            data: [u8; 24],
+        }
+
+        impl S {
+            #[no_panic]
+            fn get(&self, i: usize) -> u8 {
+                self.data[i]
+            }
+        }
+
+        fn main() {
+            let s = S { data: [0u8; 24] };
+            println!("{}", s.get(30));
+        }
+    } => "function `get`
+  module: test_method_bad
+  location: test_method_bad.rs
+"
+
+    mod test_trait_contract_bad {
+        #[no_panic(contract)]
+        trait Codec {
+            fn decode(&self, bytes: &[u8]) -> u8;
+        }
+
+        struct Nth(usize);
+
+        #[no_panic(contract)]
+        impl Codec for Nth {
+            fn decode(&self, bytes: &[u8]) -> u8 {
+                bytes[self.0]
+            }
+        }
+
+        fn main() {
+            let codec = Nth(std::env::args().count() + 30);
+            println!("{}", codec.decode(b"input string"));
+        }
+    }
+
+    mod test_inline_module_bad {
+        #[no_panic]
+        mod decode {
+            pub mod util {
+                pub fn at(bytes: &[u8], i: usize) -> u8 {
+                    bytes[i]
+                }
+            }
+        }
+
+        fn main() {
+            println!("{}", decode::util::at(&[0u8; 16], 20));
+        }
+    } => "function `at`
+  module: test_inline_module_bad::decode::util
+  location: test_inline_module_bad.rs
+"
+
+    mod test_block_bad {
+        fn demo(bytes: &[u8], i: usize) -> u8 {
+            let first = *bytes.first().expect("nonempty");
+            let byte = no_panic::block! {
+                bytes[i]
+            };
+            first.wrapping_add(byte)
+        }
+
+        fn main() {
+            println!("{}", demo(b"input string", 30));
+        }
+    }
+
+    mod test_closure_bad {
+        fn main() {
+            let bytes = [0u8; 24];
+            let at = no_panic::closure!(move |i: usize| bytes[i]);
+            println!("{}", at(std::env::args().count() + 30));
+        }
+    }
+
+    mod test_message_args_bad {
+        #[no_panic(msg = "called while holding the device lock", id = "NET-1234", owner = "net-team")]
+        fn demo(s: &str) -> &str {
+            &s[1..]
+        }
+
+        fn main() {
+            println!("{}", demo("\u{1f980}input string"));
+        }
+    } => "function `demo`
+  module: test_message_args_bad
+  location: test_message_args_bad.rs
+  msg: called while holding the device lock
+  id: NET-1234
+  owner: net-team
+"
+
+    mod test_const_fn_bad {
+        #[no_panic]
+        const fn demo(i: u32, j: u32) -> u32 {
+            i / j
+        }
+
+        fn main() {
+            println!("{}", demo(1, std::env::args().count() as u32));
+        }
+    }
+
+    mod test_async_fn_bad {
+        use std::future::Future;
+        use std::pin::pin;
+        use std::task::{Context, Waker};
+
+        async fn yield_now() {}
+
+        #[no_panic]
+        async fn demo(s: &str) -> &str {
+            yield_now().await;
+            &s[1..]
+        }
+
+        fn main() {
+            let mut cx = Context::from_waker(Waker::noop());
+            let mut future = pin!(demo("\u{1f980}input string"));
+            println!("{:?}", future.as_mut().poll(&mut cx));
+        }
+    }
+
+    mod test_async_poll_bad {
+        use std::future::Future;
+        use std::pin::{pin, Pin};
+        use std::task::{Context, Poll, Waker};
+
+        struct At<'a>(&'a [u8], usize);
+
+        impl Future for At<'_> {
+            type Output = u8;
+            fn poll(self: Pin<&mut Self>, _cx: &mut Context) -> Poll<u8> {
+                Poll::Ready(self.0[self.1])
+            }
+        }
+
+        #[no_panic(poll)]
+        async fn demo(bytes: &[u8], i: usize) -> u8 {
+            At(bytes, i).await
+        }
+
+        fn main() {
+            let mut cx = Context::from_waker(Waker::noop());
+            let i = std::env::args().count() + 30;
+            let mut future = pin!(demo(b"input string", i));
+            println!("{:?}", future.as_mut().poll(&mut cx));
+        }
+    }
+
+    mod test_granular_bad {
+        #[no_panic(granular)]
+        fn demo(bytes: &[u8], i: usize) -> u8 {
+            let first = bytes.first().copied().unwrap_or(0);
+            let byte = bytes[i];
+            first.wrapping_add(byte)
+        }
+
+        fn main() {
+            println!("{}", demo(b"input string", 30));
+        }
+    } => "statement in function `demo`
+  module: test_granular_bad
+  location: test_granular_bad.rs
+"
+
+    #[with_cfg(no_panic_verify)]
+    mod test_unused_bad {
+        #[no_panic(verify)]
+        fn demo(s: &str) -> &str {
+            &s[1..]
+        }
+
+        fn main() {}
+    }
+
+    #[with_cfg(no_panic_verify)]
+    mod test_unused_mod_bad {
+        #[no_panic]
+        mod util {
+            pub fn demo(s: &str) -> &str {
+                &s[1..]
+            }
+        }
+
+        fn main() {}
+    }
+
+    #[with_cfg(no_panic_verify)]
+    mod test_unused_method_bad {
+        pub struct S([u8; 2]);
+
+        #[no_panic]
+        impl S {
+            fn get(&self, i: usize) -> u8 {
+                self.0[i]
+            }
+        }
+
+        fn main() {}
+    }
+
+    #[with_cfg(no_panic_verify)]
+    mod test_instantiate_bad {
+        #[no_panic(instantiate(u8, Vec<u8>))]
+        fn nth<T: Clone>(slice: &[T]) -> T {
+            slice[3].clone()
+        }
+
+        fn main() {}
+    } => "function `nth::<Vec<u8>>`
+  module: test_instantiate_bad
+  location: test_instantiate_bad.rs
+"
+
+    #[with_codegen(debug-assertions = on)]
+    mod test_debug_default_bad {
+        #[no_panic]
+        fn demo(s: &str) -> &str {
+            &s[1..]
+        }
+
+        fn main() {
+            println!("{}", demo("\u{1f980}input string"));
+        }
+    }
+
+    #[with_codegen(debug-assertions = off)]
+    mod test_debug_off_release_bad {
+        #[no_panic(debug = "off")]
+        fn demo(s: &str) -> &str {
+            &s[1..]
+        }
+
+        fn main() {
+            println!("{}", demo("\u{1f980}input string"));
+        }
+    }
+
+    #[with_cfg(no_panic_debug = "off")]
+    #[with_codegen(debug-assertions = on)]
+    mod test_debug_enforce_bad {
+        #[no_panic(debug = "enforce")]
+        fn demo(s: &str) -> &str {
+            &s[1..]
+        }
+
+        fn main() {
+            println!("{}", demo("\u{1f980}input string"));
+        }
+    }
+
+    #[with_cfg(no_panic = "enforce")]
+    mod test_mode_enforce_bad {
+        #[no_panic]
+        fn demo(s: &str) -> &str {
+            &s[1..]
+        }
+
+        fn main() {
+            println!("{}", demo("\u{1f980}input string"));
+        }
+    }
+];
+
+assert_abort![
+    #[with_codegen(debug-assertions = on)]
+    mod test_debug_abort_bad {
+        #[no_panic(debug = "abort")]
+        fn demo(s: &str) -> &str {
+            &s[1..]
+        }
+
+        fn main() {
+            println!("{}", demo("\u{1f980}input string"));
+        }
+    }
+
+    #[with_cfg(no_panic = "abort")]
+    mod test_mode_abort_bad {
+        #[no_panic]
+        fn demo(s: &str) -> &str {
+            &s[1..]
+        }
+
+        fn main() {
+            println!("{}", demo("\u{1f980}input string"));
+        }
+    }
+];
+
+assert_listed_abort![
+    mod test_listed_bad {
+        #[no_panic]
+        fn demo(s: &str) -> &str {
+            &s[1..]
+        }
+
+        fn main() {
+            println!("{}", demo("\u{1f980}input string"));
+        }
+    }
+
+    mod test_listed_generic_bad {
+        #[no_panic]
+        fn nth<T: Copy>(v: &[T], i: usize) -> T {
+            v[i]
+        }
+
+        fn main() {
+            let i = std::env::args().count() + 2;
+            println!("{}", nth(&[1u8], i));
+        }
+    }
+];
+
+assert_compile_warning![
+    #[with_codegen(panic = abort)]
+    mod test_panic_abort_bad {
+        #[no_panic]
+        fn demo(s: &str) -> &str {
+            &s[1..]
+        }
+
+        fn main() {
+            println!("{}", demo("\u{1f980}input string"));
+        }
+    } => "no_panic cannot detect panics in a build with panic = \"abort\""
 ];
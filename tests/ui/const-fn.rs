@@ -1,6 +0,0 @@
-use no_panic::no_panic;
-
-#[no_panic]
-const fn f() {}
-
-fn main() {}
@@ -0,0 +1,17 @@
+use no_panic::no_panic;
+
+#[no_panic(instantiate(u8))]
+fn f() {}
+
+#[no_panic(instantiate(u8, <u8, u16>))]
+fn g<T>(_t: T) {}
+
+struct S;
+
+#[no_panic(instantiate(u8))]
+impl S {}
+
+#[no_panic(instantiate(u8))]
+async fn h<T>(_t: T) {}
+
+fn main() {}
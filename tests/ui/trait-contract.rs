@@ -0,0 +1,43 @@
+use no_panic::no_panic;
+
+#[no_panic(contract)]
+trait Codec {
+    fn decode(&self, bytes: &[u8]) -> Option<u8>;
+}
+
+struct First;
+
+impl Codec for First {
+    fn decode(&self, bytes: &[u8]) -> Option<u8> {
+        bytes.first().copied()
+    }
+}
+
+struct Last;
+
+#[no_panic(contract)]
+impl Codec for Last {
+    #[no_panic(skip)]
+    fn decode(&self, bytes: &[u8]) -> Option<u8> {
+        bytes.last().copied()
+    }
+}
+
+#[no_panic(contract)]
+impl First {}
+
+#[no_panic]
+mod nested {
+    #[no_panic(contract)]
+    pub trait Encode {
+        fn encode(&self) -> u8;
+    }
+}
+
+impl nested::Encode for First {
+    fn encode(&self) -> u8 {
+        0
+    }
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use no_panic::no_panic;
+
+#[no_panic(message = "must not panic")]
+fn f() {}
+
+#[no_panic(msg = "must not panic", msg = "really")]
+fn g() {}
+
+#[no_panic(debug = "skip")]
+fn h() {}
+
+fn main() {}